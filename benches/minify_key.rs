@@ -90,7 +90,7 @@ pub fn bench_t(c: &mut Criterion) {
 
     c.bench_function("format! (many)", |b| {
         b.iter(|| {
-            format!(
+            let _ = format!(
                 r#"Hello {name} %{surname}, your account id is {id}, email address is {email}.
         You live in {city} {zip}.
         Your website is {website}."#,
@@ -91,6 +91,7 @@ fn add_translations(
             key: item.1.clone(),
             index,
             minify_key: *minify_key,
+            default: None,
             locations: vec![],
         });
     }
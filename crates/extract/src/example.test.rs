@@ -18,5 +18,13 @@ and support mutiple YAML files merging."##);
         t!("The table below describes some of those behaviours.");
         // Will remove spaces for avoid duplication.
         t!("The table     below describes some     of those behaviours.");
+
+        // The escaped placeholders are kept as-is.
+        t!("Use %%{name} to show the name, like %{name}.", name = "Jason");
+
+        // The default text is the initial value, the first one is used.
+        t!("checkout.title", default = "Checkout, %{name}", name = default);
+        t!("checkout.title", default = "Other");
+        t!("checkout.total", count = 1, default => "Total");
     }
 }
@@ -18,6 +18,8 @@ pub struct Message {
     pub key: String,
     pub index: usize,
     pub minify_key: bool,
+    /// The `default` argument of `t!`, the initial value of the generated translations.
+    pub default: Option<String>,
     pub locations: Vec<Location>,
 }
 
@@ -27,6 +29,7 @@ impl Message {
             key: key.to_owned(),
             index,
             minify_key,
+            default: None,
             locations: vec![],
         }
     }
@@ -123,6 +126,9 @@ impl<'a> Extractor<'a> {
                     .results
                     .entry(message_key)
                     .or_insert_with(|| Message::new(&message_content, index, *minify_key));
+                if message.default.is_none() {
+                    message.default = take_default(token_iter);
+                }
 
                 let span = lit.span();
                 let line = span.start().line;
@@ -137,6 +143,27 @@ impl<'a> Extractor<'a> {
     }
 }
 
+/// Find the literal `default = "..."` (or `default => "..."`) argument in the rest arguments of `t!`.
+fn take_default(token_iter: impl Iterator<Item = TokenTree>) -> Option<String> {
+    let tokens = token_iter.collect::<Vec<_>>();
+    tokens.windows(3).enumerate().find_map(|(i, window)| {
+        let [TokenTree::Punct(comma), TokenTree::Ident(name), TokenTree::Punct(eq)] = window else {
+            return None;
+        };
+        if comma.as_char() != ',' || name != "default" || eq.as_char() != '=' {
+            return None;
+        }
+        let value = match tokens.get(i + 3)? {
+            TokenTree::Punct(arrow) if arrow.as_char() == '>' => tokens.get(i + 4)?,
+            value => value,
+        };
+        match value {
+            TokenTree::Literal(lit) => literal_to_string(lit),
+            _ => None,
+        }
+    })
+}
+
 fn literal_to_string(lit: &proc_macro2::Literal) -> Option<String> {
     match syn::parse_str::<syn::LitStr>(&lit.to_string()) {
         Ok(lit) => Some(lit.value()),
@@ -171,6 +198,7 @@ mod tests {
                     ],
                     index: 0,
                     minify_key: false,
+                    default: None,
                 };
                 results.push(message);
             )+
@@ -218,7 +246,7 @@ mod tests {
         let source = include_str!("example.test.rs");
         let stream = proc_macro2::TokenStream::from_str(source).unwrap();
 
-        let expected = build_messages![
+        let mut expected = build_messages![
             ("hello", 4),
             ("views.message.title", 5),
             ("views.message.description", 7),
@@ -231,8 +259,13 @@ mod tests {
                 "The table below describes some of those behaviours.",
                 18,
                 20
-            )
+            ),
+            ("Use %%{name} to show the name, like %{name}.", 23),
+            ("checkout.title", 26, 27),
+            ("checkout.total", 28)
         ];
+        expected[6].default = Some("Checkout, %{name}".into());
+        expected[7].default = Some("Total".into());
 
         let mut results = HashMap::new();
 
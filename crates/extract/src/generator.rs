@@ -1,5 +1,5 @@
 use crate::extractor::Message;
-use rust_i18n_support::load_locales;
+use rust_i18n_support::{dump_translations_v2, load_locales, LocaleFormat};
 use std::collections::HashMap;
 use std::io::prelude::*;
 use std::io::Result;
@@ -13,7 +13,7 @@ pub fn generate<'a, P: AsRef<Path>>(
     messages: impl IntoIterator<Item = (&'a String, &'a Message)> + Clone,
 ) -> Result<()> {
     let filename = "TODO.yml";
-    let format = "yaml";
+    let format = LocaleFormat::Yaml;
 
     let trs = generate_result(&output_path, filename, all_locales, messages);
 
@@ -35,28 +35,8 @@ pub fn generate<'a, P: AsRef<Path>>(
     Err(err)
 }
 
-fn convert_text(trs: &Translations, format: &str) -> String {
-    let mut value = serde_json::Value::Object(serde_json::Map::new());
-    value["_version"] = serde_json::Value::Number(serde_json::Number::from(2));
-
-    for (key, val) in trs {
-        let mut obj = serde_json::Value::Object(serde_json::Map::new());
-        for (locale, text) in val {
-            obj[locale] = serde_json::Value::String(text.clone());
-        }
-        value[key] = obj;
-    }
-
-    match format {
-        "json" => serde_json::to_string_pretty(&value).unwrap(),
-        "yaml" | "yml" => {
-            let text = serde_yml::to_string(&value).unwrap();
-            // Remove leading `---`
-            text.trim_start_matches("---").trim_start().to_string()
-        }
-        "toml" => toml::to_string_pretty(&value).unwrap(),
-        _ => unreachable!(),
-    }
+fn convert_text(trs: &Translations, format: LocaleFormat) -> String {
+    dump_translations_v2(trs, format)
 }
 
 fn generate_result<'a, P: AsRef<Path>>(
@@ -91,10 +71,12 @@ fn generate_result<'a, P: AsRef<Path>>(
                 }
             }
 
-            let value = if m.minify_key {
+            let value = if let Some(default) = &m.default {
+                default.to_owned()
+            } else if m.minify_key {
                 m.key.to_owned()
             } else {
-                m.key.split('.').last().unwrap_or_default().to_string()
+                m.key.split('.').next_back().unwrap_or_default().to_string()
             };
 
             trs.entry(key.clone())
@@ -137,7 +119,7 @@ mod tests {
     #[test]
     fn test_convert_text() {
         let mut trs = Translations::new();
-        let format = "json";
+        let format = LocaleFormat::Json;
 
         let result = convert_text(&trs, format);
         let expect = r#"
@@ -166,7 +148,7 @@ mod tests {
         "#;
         assert_eq_json(&result, expect);
 
-        let format = "yaml";
+        let format = LocaleFormat::Yaml;
         let result = convert_text(&trs, format);
         let expect = indoc! {r#"
         _version: 2
@@ -176,7 +158,7 @@ mod tests {
         "#};
         assert_eq!(&result, &expect);
 
-        let format = "toml";
+        let format = LocaleFormat::Toml;
         let result = convert_text(&trs, format);
         let expect = indoc! {r#"
         _version = 2
@@ -187,4 +169,28 @@ mod tests {
         "#};
         assert_eq!(&result, &expect);
     }
+
+    #[test]
+    fn test_generate_result_default() {
+        let mut messages = HashMap::new();
+        messages.insert(
+            "checkout.title".to_string(),
+            Message {
+                key: "checkout.title".into(),
+                default: Some("Checkout".into()),
+                ..Default::default()
+            },
+        );
+        messages.insert(
+            "checkout.total".to_string(),
+            Message {
+                key: "checkout.total".into(),
+                ..Default::default()
+            },
+        );
+
+        let trs = generate_result("./not-exists", "TODO.yml", &vec!["en".into()], &messages);
+        assert_eq!(trs["checkout.title"]["en"], "Checkout");
+        assert_eq!(trs["checkout.total"]["en"], "total");
+    }
 }
@@ -1,23 +1,34 @@
 use quote::quote;
 use rust_i18n_support::{
-    is_debug, load_locales, I18nConfig, DEFAULT_MINIFY_KEY, DEFAULT_MINIFY_KEY_LEN,
-    DEFAULT_MINIFY_KEY_PREFIX, DEFAULT_MINIFY_KEY_THRESH,
+    canonicalize_locale, find_links, is_debug, load_locales, locale_files, locate_translation,
+    parse_segments, I18nConfig, IcuMessage, Segment, DATETIME_STYLES, DEFAULT_MINIFY_KEY,
+    DEFAULT_MINIFY_KEY_LEN, DEFAULT_MINIFY_KEY_PREFIX, DEFAULT_MINIFY_KEY_THRESH,
+    MESSAGE_FORMAT_DEFAULT, MESSAGE_FORMAT_ICU,
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use syn::{parse_macro_input, Expr, Ident, LitBool, LitStr, Token};
 
 mod minify_key;
 mod tr;
+mod translations;
+mod typed_keys;
 
 struct Args {
     locales_path: String,
     default_locale: Option<String>,
     fallback: Option<Vec<String>>,
+    fallback_map: BTreeMap<String, Vec<String>>,
     extend: Option<Expr>,
     minify_key: bool,
     minify_key_len: usize,
     minify_key_prefix: String,
     minify_key_thresh: usize,
+    message_format: String,
+    check_placeholders: bool,
+    strict: bool,
+    typed_keys: Option<String>,
+    locale_from_env: bool,
+    pseudo_locales: bool,
 }
 
 impl Args {
@@ -29,13 +40,39 @@ impl Args {
     }
 
     fn consume_fallback(&mut self, input: syn::parse::ParseStream) -> syn::parse::Result<()> {
+        self.fallback = Some(Self::parse_locales(input, "fallback")?);
+        Ok(())
+    }
+
+    /// Parse `{ "zh-HK" = ["zh-TW", "zh-Hant"], "pt-BR" = "pt-PT" }`.
+    fn consume_fallback_map(&mut self, input: syn::parse::ParseStream) -> syn::parse::Result<()> {
+        let content;
+        syn::braced!(content in input);
+        while !content.is_empty() {
+            let locale = content.parse::<LitStr>()?.value();
+            content.parse::<Token![=]>()?;
+            let fallback = Self::parse_locales(&content, "fallback_map")?;
+            self.fallback_map.insert(locale, fallback);
+            if content.parse::<Token![,]>().is_err() {
+                break;
+            }
+        }
+        if !content.is_empty() {
+            return Err(content.error("expected `,`"));
+        }
+        Ok(())
+    }
+
+    /// Parse a string literal or an array of string literals as the locales.
+    fn parse_locales(
+        input: syn::parse::ParseStream,
+        name: &str,
+    ) -> syn::parse::Result<Vec<String>> {
         if let Ok(val) = input.parse::<LitStr>() {
-            self.fallback = Some(vec![val.value()]);
-            return Ok(());
+            return Ok(vec![val.value()]);
         }
         let val = input.parse::<syn::ExprArray>()?;
-        let fallback = val
-            .elems
+        val.elems
             .into_iter()
             .map(|expr| {
                 if let syn::Expr::Lit(syn::ExprLit {
@@ -45,14 +82,13 @@ impl Args {
                 {
                     Ok(lit_str.value())
                 } else {
-                    Err(input.error(
-                        "`fallback` must be a string literal or an array of string literals",
-                    ))
+                    Err(input.error(format!(
+                        "`{}` must be a string literal or an array of string literals",
+                        name
+                    )))
                 }
             })
-            .collect::<syn::parse::Result<Vec<String>>>()?;
-        self.fallback = Some(fallback);
-        Ok(())
+            .collect()
     }
 
     fn consume_minify_key(&mut self, input: syn::parse::ParseStream) -> syn::parse::Result<()> {
@@ -85,6 +121,61 @@ impl Args {
         Ok(())
     }
 
+    fn consume_message_format(&mut self, input: syn::parse::ParseStream) -> syn::parse::Result<()> {
+        let lit_str = input.parse::<syn::LitStr>()?;
+        let message_format = lit_str.value();
+        if ![MESSAGE_FORMAT_DEFAULT, MESSAGE_FORMAT_ICU].contains(&message_format.as_str()) {
+            return Err(syn::Error::new_spanned(
+                lit_str,
+                "`message_format` must be `\"default\"` or `\"icu\"`",
+            ));
+        }
+        self.message_format = message_format;
+        Ok(())
+    }
+
+    fn consume_check_placeholders(
+        &mut self,
+        input: syn::parse::ParseStream,
+    ) -> syn::parse::Result<()> {
+        let lit_bool = input.parse::<LitBool>()?;
+        self.check_placeholders = lit_bool.value;
+        Ok(())
+    }
+
+    fn consume_strict(&mut self, input: syn::parse::ParseStream) -> syn::parse::Result<()> {
+        let lit_bool = input.parse::<LitBool>()?;
+        self.strict = lit_bool.value;
+        Ok(())
+    }
+
+    fn consume_typed_keys(&mut self, input: syn::parse::ParseStream) -> syn::parse::Result<()> {
+        let lit_str = input.parse::<LitStr>()?;
+        if lit_str.parse::<Ident>().is_err() {
+            return Err(syn::Error::new_spanned(
+                lit_str,
+                "`typed_keys` must be a module name",
+            ));
+        }
+        self.typed_keys = Some(lit_str.value());
+        Ok(())
+    }
+
+    fn consume_locale_from_env(
+        &mut self,
+        input: syn::parse::ParseStream,
+    ) -> syn::parse::Result<()> {
+        let lit_bool = input.parse::<LitBool>()?;
+        self.locale_from_env = lit_bool.value;
+        Ok(())
+    }
+
+    fn consume_pseudo_locales(&mut self, input: syn::parse::ParseStream) -> syn::parse::Result<()> {
+        let lit_bool = input.parse::<LitBool>()?;
+        self.pseudo_locales = lit_bool.value;
+        Ok(())
+    }
+
     fn consume_options(&mut self, input: syn::parse::ParseStream) -> syn::parse::Result<()> {
         let ident = input.parse::<Ident>()?.to_string();
         input.parse::<Token![=]>()?;
@@ -94,6 +185,9 @@ impl Args {
             "fallback" => {
                 self.consume_fallback(input)?;
             }
+            "fallback_map" => {
+                self.consume_fallback_map(input)?;
+            }
             "backend" => {
                 let val = input.parse::<Expr>()?;
                 self.extend = Some(val);
@@ -110,6 +204,24 @@ impl Args {
             "minify_key_thresh" => {
                 self.consume_minify_key_thresh(input)?;
             }
+            "message_format" => {
+                self.consume_message_format(input)?;
+            }
+            "check_placeholders" => {
+                self.consume_check_placeholders(input)?;
+            }
+            "strict" => {
+                self.consume_strict(input)?;
+            }
+            "typed_keys" => {
+                self.consume_typed_keys(input)?;
+            }
+            "locale_from_env" => {
+                self.consume_locale_from_env(input)?;
+            }
+            "pseudo_locales" => {
+                self.consume_pseudo_locales(input)?;
+            }
             _ => {}
         }
 
@@ -121,6 +233,28 @@ impl Args {
         Ok(())
     }
 
+    /// Canonicalize the default and fallback locales, as the locales of the translations.
+    fn canonicalize_locales(&mut self) {
+        if let Some(default_locale) = &mut self.default_locale {
+            *default_locale = canonicalize_locale(default_locale).into_owned();
+        }
+        if let Some(fallback) = &mut self.fallback {
+            for locale in fallback.iter_mut() {
+                *locale = canonicalize_locale(locale).into_owned();
+            }
+        }
+        self.fallback_map = std::mem::take(&mut self.fallback_map)
+            .into_iter()
+            .map(|(locale, fallback)| {
+                let fallback = fallback
+                    .iter()
+                    .map(|locale| canonicalize_locale(locale).into_owned())
+                    .collect();
+                (canonicalize_locale(&locale).into_owned(), fallback)
+            })
+            .collect();
+    }
+
     /// Load the config from Cargo.toml. This can be overridden by subsequent options.
     fn load_metadata(&mut self, input: syn::parse::ParseStream) -> syn::parse::Result<()> {
         // CARGO_MANIFEST_DIR is current build directory
@@ -134,10 +268,17 @@ impl Args {
             if !cfg.fallback.is_empty() {
                 self.fallback = Some(cfg.fallback);
             }
+            self.fallback_map = cfg.fallback_map;
             self.minify_key = cfg.minify_key;
             self.minify_key_len = cfg.minify_key_len;
             self.minify_key_prefix = cfg.minify_key_prefix;
             self.minify_key_thresh = cfg.minify_key_thresh;
+            self.message_format = cfg.message_format;
+            self.check_placeholders = cfg.check_placeholders;
+            self.strict = cfg.strict;
+            self.typed_keys = cfg.typed_keys;
+            self.locale_from_env = cfg.locale_from_env;
+            self.pseudo_locales = cfg.pseudo_locales;
         } else if rust_i18n_support::is_debug() {
             return Err(input.error("The CARGO_MANIFEST_DIR is required fo `metadata`"));
         }
@@ -163,6 +304,9 @@ impl syn::parse::Parse for Args {
     /// # fn v4() {
     /// i18n!("locales", fallback = ["en", "es"]);
     /// # }
+    /// # fn v4_1() {
+    /// i18n!("locales", fallback = "en", fallback_map = { "zh-HK" = ["zh-TW", "zh-Hant"], "pt-BR" = "pt-PT" });
+    /// # }
     /// # fn v5() {
     /// i18n!("locales", fallback = ["en", "es"],
     ///       minify_key = true,
@@ -170,6 +314,21 @@ impl syn::parse::Parse for Args {
     ///       minify_key_prefix = "t_",
     ///       minify_key_thresh = 64);
     /// # }
+    /// # fn v6() {
+    /// i18n!("locales", message_format = "icu");
+    /// # }
+    /// # fn v7() {
+    /// i18n!("locales", check_placeholders = true, strict = true);
+    /// # }
+    /// # fn v8() {
+    /// i18n!("locales", typed_keys = "tr");
+    /// # }
+    /// # fn v9() {
+    /// i18n!("locales", locale_from_env = true);
+    /// # }
+    /// # fn v10() {
+    /// i18n!("locales", pseudo_locales = true);
+    /// # }
     /// ```
     ///
     /// Ref: https://docs.rs/syn/latest/syn/parse/index.html
@@ -181,11 +340,18 @@ impl syn::parse::Parse for Args {
             locales_path: String::from("locales"),
             default_locale: None,
             fallback: None,
+            fallback_map: BTreeMap::new(),
             extend: None,
             minify_key: DEFAULT_MINIFY_KEY,
             minify_key_len: DEFAULT_MINIFY_KEY_LEN,
             minify_key_prefix: DEFAULT_MINIFY_KEY_PREFIX.to_owned(),
             minify_key_thresh: DEFAULT_MINIFY_KEY_THRESH,
+            message_format: MESSAGE_FORMAT_DEFAULT.to_owned(),
+            check_placeholders: false,
+            strict: false,
+            typed_keys: None,
+            locale_from_env: false,
+            pseudo_locales: false,
         };
 
         result.load_metadata(input)?;
@@ -211,6 +377,8 @@ impl syn::parse::Parse for Args {
 /// # Attributes
 ///
 /// - `fallback` for set the fallback locale, if present [`t!`](macro.t.html) macro will use it as the fallback locale.
+/// - `fallback_map` for set the fallback locales of each locale, such as `{ "zh-HK" = ["zh-TW", "zh-Hant"] }`.
+///   * They are looked up after the locale itself, before the lookup of its language territory and the `fallback` locales.
 /// - `backend` for set the backend, if present [`t!`](macro.t.html) macro will use it as the backend.
 /// - `metadata` to enable/disable loading of the [package.metadata.i18n] config from Cargo.toml, default: `true`.
 /// - `minify_key` for enable/disable minify key, default: [`DEFAULT_MINIFY_KEY`](constant.DEFAULT_MINIFY_KEY.html).
@@ -219,6 +387,19 @@ impl syn::parse::Parse for Args {
 /// - `minify_key_prefix` for set the minify key prefix, default: [`DEFAULT_MINIFY_KEY_PREFIX`](constant.DEFAULT_MINIFY_KEY_PREFIX.html).
 /// - `minify_key_thresh` for set the minify key threshold, default: [`DEFAULT_MINIFY_KEY_THRESH`](constant.DEFAULT_MINIFY_KEY_THRESH.html).
 ///   * If the length of the value is less than or equal to this value, the value will not be minified.
+/// - `message_format` for set the syntax of the translations, `"default"` for `%{name}` or `"icu"` for ICU MessageFormat, default: `"default"`.
+///   * The ICU messages are parsed at compile time, and any syntax error fails the compilation.
+/// - `check_placeholders` for enable/disable checking the arguments of [`t!`](macro.t.html) against the placeholders of the translations at compile time, default: `false`.
+///   * Only the literal keys are checked, and any missing or unused argument in any locale fails the compilation.
+/// - `strict` for enable/disable checking the literal keys of [`t!`](macro.t.html) exist in the default locale at compile time, default: `false`.
+///   * The minified key is checked if `minify_key` is enabled, and `t!("key", strict = false)` skips the check for a call.
+/// - `typed_keys` for generate a module with the given name, which has a function for each key of the default locale, default: none.
+///   * For example, `views.message.title: "Hello, %{name}"` generates `tr::views::message::title(name)` with `typed_keys = "tr"`.
+/// - `locale_from_env` for enable/disable setting the current locale from the `LC_ALL`, `LC_MESSAGES`, `LANG` and `LANGUAGE` environment variables, default: `false`.
+///   * The locale is negotiated against the available locales when the first `t!` without `locale` is called, unless it's already set with `set_locale`, see `rust_i18n::init_from_env!`.
+/// - `pseudo_locales` for enable/disable the `en-XA` (accented and expanded) and `ar-XB` (mirrored right-to-left) pseudo-locales, default: `false`.
+///   * They are generated from the default locale of the locale files when the translations are first used, before the `backend` is added, so the translations of the `backend` are not pseudo-localized.
+///     They are selected with `set_locale` like the other locales.
 ///
 /// ```no_run
 /// # use rust_i18n::i18n;
@@ -234,6 +415,9 @@ impl syn::parse::Parse for Args {
 /// # fn v4() {
 /// i18n!("locales", fallback = ["en", "es"]);
 /// # }
+/// # fn v4_1() {
+/// i18n!("locales", fallback = "en", fallback_map = { "zh-HK" = ["zh-TW", "zh-Hant"], "pt-BR" = "pt-PT" });
+/// # }
 /// # fn v5() {
 /// i18n!("locales", fallback = ["en", "es"],
 ///       minify_key = true,
@@ -244,18 +428,69 @@ impl syn::parse::Parse for Args {
 /// # fn v6() {
 /// i18n!();
 /// # }
+/// # fn v7() {
+/// i18n!("locales", message_format = "icu");
+/// # }
+/// # fn v8() {
+/// i18n!("locales", check_placeholders = true, strict = true);
+/// # }
+/// # fn v9() {
+/// i18n!("locales", typed_keys = "tr");
+/// # }
+/// # fn v10() {
+/// i18n!("locales", locale_from_env = true);
+/// # }
+/// # fn v11() {
+/// i18n!("locales", pseudo_locales = true);
+/// # }
 /// ```
 #[proc_macro]
 pub fn i18n(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let args = parse_macro_input!(input as Args);
+    let mut args = parse_macro_input!(input as Args);
+    args.canonicalize_locales();
 
     // CARGO_MANIFEST_DIR is current build directory
     let cargo_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is empty");
     let current_dir = std::path::PathBuf::from(cargo_dir);
     let locales_path = current_dir.join(&args.locales_path);
 
-    let data = load_locales(&locales_path.display().to_string(), |_| false);
-    let code = generate_code(data, args);
+    let locales_path = locales_path.display().to_string();
+    let data = load_locales(&locales_path, |_| false);
+    if args.check_placeholders || args.strict {
+        translations::store(&locales_path, data.clone());
+    }
+
+    let mut errors = proc_macro2::TokenStream::new();
+    if args.message_format == MESSAGE_FORMAT_ICU {
+        if let Err(err) = validate_icu_messages(&locales_path, &data) {
+            errors.extend(err.to_compile_error());
+        }
+    }
+    if args.message_format != MESSAGE_FORMAT_ICU && !cfg!(feature = "datetime") {
+        if let Err(err) = validate_datetime_styles(&locales_path, &data) {
+            errors.extend(err.to_compile_error());
+        }
+    }
+    if let Err(err) = validate_links(
+        &data,
+        args.fallback.as_deref().unwrap_or_default(),
+        &args.fallback_map,
+    ) {
+        errors.extend(err.to_compile_error());
+    }
+
+    // Include the locale files, so the crate is rebuilt when they change
+    let tracked_files = locale_files(&locales_path).into_iter().map(|path| {
+        let path = path.display().to_string();
+        quote! { const _: &[u8] = include_bytes!(#path); }
+    });
+    let tracked_files = quote! { #(#tracked_files)* };
+
+    // The `t!` checks need the absolute path to find the translations
+    args.locales_path = locales_path;
+    let mut code = generate_code(data, args);
+    code.extend(tracked_files);
+    code.extend(errors);
 
     if is_debug() {
         println!(
@@ -267,6 +502,175 @@ pub fn i18n(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     code.into()
 }
 
+/// Parse all translations as ICU messages, and returns the syntax errors with the locale file.
+fn validate_icu_messages(
+    locales_path: &str,
+    translations: &HashMap<String, HashMap<String, String>>,
+) -> syn::parse::Result<()> {
+    let mut errors = translations
+        .iter()
+        .flat_map(|(locale, trs)| {
+            trs.iter().filter_map(move |(key, value)| {
+                // The links are resolved before formatting
+                let value = translations::resolve_links(trs, value);
+                let err = IcuMessage::parse(&value).err()?;
+                let file = locate_translation(locales_path, locale, key)
+                    .map_or_else(|| locales_path.to_string(), |p| p.display().to_string());
+                Some(format!(
+                    "Invalid ICU message in `{}` for `{}` in locale `{}`: {}",
+                    file, key, locale, err
+                ))
+            })
+        })
+        .collect::<Vec<_>>();
+    errors.sort();
+    combine_errors(errors)
+}
+
+/// Find the date and time styles of the placeholders, such as `%{when:date_short}`, which need the `datetime` feature.
+fn validate_datetime_styles(
+    locales_path: &str,
+    translations: &HashMap<String, HashMap<String, String>>,
+) -> syn::parse::Result<()> {
+    let mut errors = translations
+        .iter()
+        .flat_map(|(locale, trs)| {
+            trs.iter().flat_map(move |(key, value)| {
+                parse_segments(value)
+                    .filter_map(|segment| match segment {
+                        Segment::Placeholder(placeholder) => {
+                            let (_, style) = placeholder.split_once(':')?;
+                            DATETIME_STYLES.contains(&style).then_some(placeholder)
+                        }
+                        Segment::Text(_) => None,
+                    })
+                    .map(move |placeholder| {
+                        let file = locate_translation(locales_path, locale, key)
+                            .map_or_else(|| locales_path.to_string(), |p| p.display().to_string());
+                        format!(
+                            "The `%{{{}}}` placeholder in `{}` for `{}` in locale `{}` needs the `datetime` feature of rust-i18n",
+                            placeholder, file, key, locale
+                        )
+                    })
+            })
+        })
+        .collect::<Vec<_>>();
+    errors.sort();
+    combine_errors(errors)
+}
+
+/// Find the cycles of the `@:key` links, which are resolved with the locale and its fallback locales like `t!`.
+fn validate_links(
+    translations: &HashMap<String, HashMap<String, String>>,
+    fallback: &[String],
+    fallback_map: &BTreeMap<String, Vec<String>>,
+) -> syn::parse::Result<()> {
+    let mut locales = translations.keys().collect::<Vec<_>>();
+    locales.sort();
+
+    let mut errors = vec![];
+    for locale in locales {
+        let chain = fallback_chain(locale, fallback, fallback_map);
+        let lookup = |key: &str| {
+            chain
+                .iter()
+                .find_map(|locale| translations.get(*locale)?.get(key))
+        };
+        let mut keys = chain
+            .iter()
+            .filter_map(|locale| translations.get(*locale))
+            .flat_map(|trs| trs.keys())
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+
+        // The keys in the current path are `false`, and the checked keys are `true`
+        let mut visited = HashMap::<&str, bool>::new();
+        let mut path = vec![];
+        for key in keys {
+            if let Some(cycle) = find_link_cycle(key, &lookup, &mut visited, &mut path) {
+                errors.push(format!(
+                    "Cyclic linked messages in locale `{}`: {}",
+                    locale,
+                    cycle
+                        .iter()
+                        .map(|key| format!("`{}`", key))
+                        .collect::<Vec<_>>()
+                        .join(" -> ")
+                ));
+            }
+        }
+    }
+    combine_errors(errors)
+}
+
+fn find_link_cycle<'a>(
+    key: &'a str,
+    lookup: &impl Fn(&str) -> Option<&'a String>,
+    visited: &mut HashMap<&'a str, bool>,
+    path: &mut Vec<&'a str>,
+) -> Option<Vec<&'a str>> {
+    match visited.get(key) {
+        Some(true) => return None,
+        Some(false) => {
+            let start = path.iter().position(|k| *k == key).unwrap_or_default();
+            let mut cycle = path[start..].to_vec();
+            cycle.push(key);
+            return Some(cycle);
+        }
+        None => {}
+    }
+
+    visited.insert(key, false);
+    path.push(key);
+    let cycle = lookup(key).and_then(|value| {
+        find_links(value)
+            .into_iter()
+            .find_map(|link| find_link_cycle(link.key, lookup, visited, path))
+    });
+    path.pop();
+    visited.insert(key, true);
+    cycle
+}
+
+/// The locales to lookup for the locale, in the same order as `_rust_i18n_find_map`.
+fn fallback_chain<'a>(
+    locale: &'a str,
+    fallback: &'a [String],
+    fallback_map: &'a BTreeMap<String, Vec<String>>,
+) -> Vec<&'a str> {
+    let mut chain = vec![];
+    let mut current_locale = Some(locale);
+    while let Some(locale) = current_locale {
+        chain.push(locale);
+        if let Some(mapped) = fallback_map.get(locale) {
+            chain.extend(mapped.iter().map(String::as_str));
+        }
+        current_locale = locale
+            .rfind('-')
+            .map(|n| locale[..n].trim_end_matches("-x"));
+    }
+    chain.extend(fallback.iter().map(String::as_str));
+
+    let mut visited = HashSet::new();
+    chain.retain(|locale| visited.insert(*locale));
+    chain
+}
+
+/// Combine the error messages into one error.
+fn combine_errors(errors: Vec<String>) -> syn::parse::Result<()> {
+    let mut errors = errors
+        .into_iter()
+        .map(|message| syn::Error::new(proc_macro2::Span::call_site(), message));
+    match errors.next() {
+        Some(mut first) => {
+            errors.for_each(|err| first.combine(err));
+            Err(first)
+        }
+        None => Ok(()),
+    }
+}
+
 fn generate_code(
     translations: HashMap<String, HashMap<String, String>>,
     args: Args,
@@ -290,18 +694,19 @@ fn generate_code(
         });
     });
 
-    let default_locale = if let Some(default_locale) = args.default_locale {
+    let negotiate_default = args
+        .fallback
+        .as_ref()
+        .and_then(|fallback| fallback.first())
+        .or(args.default_locale.as_ref())
+        .map_or("en", |locale| locale.as_str())
+        .to_string();
+
+    let fallback_map = args.fallback_map.iter().map(|(locale, fallback)| {
         quote! {
-            use std::ops::Deref;
-            if #default_locale != rust_i18n::locale().deref() {
-                rust_i18n::set_locale(rust_i18n::locale().deref());
-            } else {
-                rust_i18n::set_locale(#default_locale);
-            }
+            (#locale, &[#(#fallback),*])
         }
-    } else {
-        quote! {}
-    };
+    });
 
     let fallback = if let Some(fallback) = args.fallback {
         quote! {
@@ -321,10 +726,67 @@ fn generate_code(
         quote! {}
     };
 
+    let pseudo_locales = if args.pseudo_locales {
+        let source_locale = args.default_locale.as_deref().unwrap_or("en");
+        quote! {
+            backend.add_pseudo_locales(#source_locale);
+        }
+    } else {
+        quote! {}
+    };
+
+    // Applied after the backend is initialized, so the locale change callbacks can use the translations
+    let locale_from_env = if args.locale_from_env {
+        quote! {
+            static _RUST_I18N_LOCALE_FROM_ENV: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+            if !_RUST_I18N_LOCALE_FROM_ENV.swap(true, std::sync::atomic::Ordering::AcqRel) {
+                if let Some(locale) = _rust_i18n_detect_locale() {
+                    rust_i18n::_init_locale(locale);
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let minify_key = args.minify_key;
     let minify_key_len = args.minify_key_len;
     let minify_key_prefix = args.minify_key_prefix;
     let minify_key_thresh = args.minify_key_thresh;
+    let typed_keys = match &args.typed_keys {
+        Some(module) => {
+            let default_locale = args.default_locale.as_deref().unwrap_or("en");
+            let typed_keys = typed_keys::TypedKeys {
+                module,
+                minify_key: args.minify_key,
+                message_format: &args.message_format,
+            };
+            typed_keys.generate(
+                &translations
+                    .get(default_locale)
+                    .cloned()
+                    .unwrap_or_default(),
+            )
+        }
+        None => quote! {},
+    };
+
+    let message_format = args.message_format;
+    let mut checks = vec![];
+    if args.check_placeholders {
+        checks.push(quote! { _check_placeholders = true });
+    }
+    if args.strict {
+        let default_locale = args.default_locale.as_deref().unwrap_or("en");
+        checks.push(quote! { _strict = true, _default_locale = #default_locale });
+    }
+    if !checks.is_empty() {
+        let locales_path = args.locales_path;
+        checks.push(quote! { _locales_path = #locales_path });
+    }
+    if args.locale_from_env {
+        checks.push(quote! { _locale_from_env = true });
+    }
 
     quote! {
         use rust_i18n::{BackendExt, CowStr, MinifyKey};
@@ -337,13 +799,15 @@ fn generate_code(
         static _RUST_I18N_BACKEND: rust_i18n::once_cell::sync::Lazy<Box<dyn rust_i18n::Backend>> = rust_i18n::once_cell::sync::Lazy::new(|| {
             let mut backend = rust_i18n::SimpleBackend::new();
             #(#all_translations)*
+            // The pseudo-locales are generated from the locale files, the extended backend can't list its translations
+            #pseudo_locales
             #extend_code
-            #default_locale
 
             Box::new(backend)
         });
 
         static _RUST_I18N_FALLBACK_LOCALE: Option<&[&'static str]> = #fallback;
+        static _RUST_I18N_FALLBACK_MAP: &[(&str, &[&str])] = &[#(#fallback_map),*];
         static _RUST_I18N_MINIFY_KEY: bool = #minify_key;
         static _RUST_I18N_MINIFY_KEY_LEN: usize = #minify_key_len;
         static _RUST_I18N_MINIFY_KEY_PREFIX: &str = #minify_key_prefix;
@@ -375,28 +839,148 @@ fn generate_code(
             })
         }
 
+        /// Walk the locale and its fallback locales, and return the first non-empty result of `f`.
+        ///
+        /// The order is: the locale itself and its `fallback_map` locales, then the RFC 4647 lookup fallbacks
+        /// (each with its `fallback_map` locales), then the `fallback` locales.
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_find_map<'l, T>(locale: &'l str, mut f: impl FnMut(&'l str) -> Option<T>) -> Option<T> {
+            let mut current_locale = Some(locale);
+            while let Some(locale) = current_locale {
+                if let Some(value) = f(locale) {
+                    return Some(value);
+                }
+                let mapped = _RUST_I18N_FALLBACK_MAP
+                    .iter()
+                    .find(|(from, _)| *from == locale)
+                    .map_or(&[][..], |(_, fallback)| *fallback);
+                if let Some(value) = mapped.iter().find_map(|locale| f(locale)) {
+                    return Some(value);
+                }
+                current_locale = _rust_i18n_lookup_fallback(locale);
+            }
+
+            _RUST_I18N_FALLBACK_LOCALE.and_then(|fallback| {
+                fallback.iter().find_map(|locale| f(locale))
+            })
+        }
+
+        /// Get the locales to look up a translation of the locale, in order.
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_fallback_chain(locale: &str) -> Vec<String> {
+            let mut chain = vec![];
+            _rust_i18n_find_map::<()>(locale, |locale| {
+                chain.push(locale.to_string());
+                None
+            });
+            chain
+        }
+
+        /// Resolve the `@:key` links in the I18n text, the linked texts are looked up with the locale and its fallback locales.
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_resolve_links<'r>(locale: &str, value: Cow<'r, str>, depth: usize) -> Cow<'r, str> {
+            if depth >= rust_i18n::MAX_LINK_DEPTH || !value.contains("@:") {
+                return value;
+            }
+            let mut resolved = std::collections::HashMap::new();
+            Cow::from(_rust_i18n_resolve_links_with(locale, &value, depth, &mut resolved))
+        }
+
+        /// Resolve the links with the resolved keys memoized, so a key linked many times is resolved once.
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        fn _rust_i18n_resolve_links_with(locale: &str, value: &str, depth: usize, resolved: &mut std::collections::HashMap<String, Option<String>>) -> String {
+            if depth >= rust_i18n::MAX_LINK_DEPTH {
+                return value.to_string();
+            }
+            rust_i18n::replace_links(value, |key| {
+                if let Some(linked) = resolved.get(key) {
+                    return linked.clone();
+                }
+                let linked = _rust_i18n_find_map(locale, |locale| _RUST_I18N_BACKEND.translate(locale, key))
+                    .map(|linked| _rust_i18n_resolve_links_with(locale, &linked, depth + 1, resolved));
+                resolved.insert(key.to_string(), linked.clone());
+                linked
+            })
+        }
+
         /// Try to get I18n text by locale and key
         #[inline]
         #[doc(hidden)]
         #[allow(missing_docs)]
         pub fn _rust_i18n_try_translate<'r>(locale: &str, key: impl AsRef<str>) -> Option<Cow<'r, str>> {
-            _RUST_I18N_BACKEND.translate(locale, key.as_ref())
-                .map(Cow::from)
-                .or_else(|| {
-                    let mut current_locale = locale;
-                    while let Some(fallback_locale) = _rust_i18n_lookup_fallback(current_locale) {
-                        if let Some(value) = _RUST_I18N_BACKEND.translate(fallback_locale, key.as_ref()) {
-                            return Some(Cow::from(value));
-                        }
-                        current_locale = fallback_locale;
-                    }
+            _rust_i18n_try_translate_in(locale, key).map(|(value, _)| value)
+        }
 
-                    _RUST_I18N_FALLBACK_LOCALE.and_then(|fallback| {
-                        fallback.iter().find_map(|locale| _RUST_I18N_BACKEND.translate(locale, key.as_ref()).map(Cow::from))
-                    })
+        /// Try to get I18n text by locale and key, with the locale that it's found in
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_try_translate_in<'r, 'l>(locale: &'l str, key: impl AsRef<str>) -> Option<(Cow<'r, str>, &'l str)> {
+            _rust_i18n_find_map(locale, |found| _RUST_I18N_BACKEND.translate(found, key.as_ref()).map(|value| (value, found)))
+                .map(|(value, found)| (_rust_i18n_resolve_links(locale, Cow::from(value), 0), found))
+        }
+
+        /// Get I18n text by locale and key, with the locale that it's found in
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_lookup<'r>(locale: &str, key: impl AsRef<str>) -> Option<rust_i18n::Resolved<'r>> {
+            let key = key.as_ref();
+            let locale = rust_i18n::canonicalize_locale(locale);
+            _rust_i18n_find_map(&locale, |found| _RUST_I18N_BACKEND.translate(found, key).map(|value| (found.to_string(), value)))
+                .map(|(found, value)| rust_i18n::Resolved {
+                    value: _rust_i18n_resolve_links(&locale, Cow::from(value), 0),
+                    via_fallback: found != *locale,
+                    locale: found,
                 })
         }
 
+        /// Try to get the plural form of I18n text by locale, key and number
+        ///
+        /// For each locale in the fallback chain, this looks up `{key}.{category}` (or `{key}.ordinal.{category}`)
+        /// with the CLDR plural category of `number` in that locale, then the `other` form, and finally `key` itself.
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_try_translate_plural<'r>(locale: &str, key: impl AsRef<str>, rule_type: rust_i18n::PluralRuleType, number: &str) -> Option<Cow<'r, str>> {
+            _rust_i18n_try_translate_plural_in(locale, key, rule_type, number).map(|(value, _)| value)
+        }
+
+        /// Try to get the plural form of I18n text by locale, key and number, with the locale that it's found in
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_try_translate_plural_in<'r, 'l>(locale: &'l str, key: impl AsRef<str>, rule_type: rust_i18n::PluralRuleType, number: &str) -> Option<(Cow<'r, str>, &'l str)> {
+            let key = key.as_ref();
+            let operands = rust_i18n::PluralOperands::parse(number);
+            let mut plural_key = String::with_capacity(key.len() + 14);
+            let requested_locale = locale;
+            _rust_i18n_find_map(locale, |locale| {
+                let category = operands
+                    .as_ref()
+                    .map_or(rust_i18n::PluralCategory::Other, |operands| rule_type.category(locale, operands));
+                [category, rust_i18n::PluralCategory::Other]
+                    .iter()
+                    .find_map(|category| {
+                        plural_key.clear();
+                        plural_key.push_str(key);
+                        plural_key.push('.');
+                        plural_key.push_str(rule_type.key_prefix());
+                        plural_key.push_str(category.as_str());
+                        _RUST_I18N_BACKEND.translate(locale, &plural_key)
+                    })
+                    .or_else(|| _RUST_I18N_BACKEND.translate(locale, key))
+                    .map(|value| (value, locale))
+            })
+            .map(|(value, found)| (_rust_i18n_resolve_links(requested_locale, Cow::from(value), 0), found))
+        }
+
         #[inline]
         #[doc(hidden)]
         #[allow(missing_docs)]
@@ -406,11 +990,60 @@ fn generate_code(
             locales
         }
 
+        /// Pick the best available locale for the requested locales with the RFC 4647 lookup,
+        /// or the first fallback locale (or the default locale) if none matches.
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_negotiate<S: AsRef<str>>(requested: &[S]) -> &'static str {
+            rust_i18n::negotiate_locale(requested, &_rust_i18n_available_locales(), _rust_i18n_lookup_fallback)
+                .unwrap_or(#negotiate_default)
+        }
+
+        /// Initialize the backend and the locale from the environment variables, unless the locale is already set,
+        /// the `t!` calls without `locale` do it before getting the current locale with `locale_from_env`.
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_init_backend() {
+            rust_i18n::once_cell::sync::Lazy::force(&_RUST_I18N_BACKEND);
+            #locale_from_env
+        }
+
+        /// Set the current locale to the best match of the locale among the available locales.
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_try_set_locale(locale: &str) -> Result<&'static str, rust_i18n::UnsupportedLocale> {
+            let selected = rust_i18n::negotiate_locale(&[locale], &_rust_i18n_available_locales(), _rust_i18n_lookup_fallback)
+                .ok_or_else(|| rust_i18n::UnsupportedLocale { locale: locale.to_string() })?;
+            rust_i18n::set_locale(selected);
+            Ok(selected)
+        }
+
+        /// Detect the locale from the environment variables, and negotiate it against the available locales.
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_detect_locale() -> Option<&'static str> {
+            rust_i18n::negotiate_locale(&rust_i18n::env_locales(), &_rust_i18n_available_locales(), _rust_i18n_lookup_fallback)
+        }
+
+        /// Set the current locale to the detected locale from the environment variables, if any.
+        #[inline]
+        #[doc(hidden)]
+        #[allow(missing_docs)]
+        pub fn _rust_i18n_init_from_env() -> Option<&'static str> {
+            let locale = _rust_i18n_detect_locale()?;
+            rust_i18n::set_locale(locale);
+            Some(locale)
+        }
+
         #[doc(hidden)]
         #[allow(unused_macros)]
         macro_rules! __rust_i18n_t {
             ($($all_tokens:tt)*) => {
-                rust_i18n::_tr!($($all_tokens)*, _minify_key = #minify_key, _minify_key_len = #minify_key_len, _minify_key_prefix = #minify_key_prefix, _minify_key_thresh = #minify_key_thresh)
+                rust_i18n::_tr!($($all_tokens)*, _minify_key = #minify_key, _minify_key_len = #minify_key_len, _minify_key_prefix = #minify_key_prefix, _minify_key_thresh = #minify_key_thresh, _message_format = #message_format #(, #checks)*)
             }
         }
 
@@ -428,6 +1061,8 @@ fn generate_code(
 
         pub(crate) use __rust_i18n_t as _rust_i18n_t;
         pub(crate) use __rust_i18n_tkv as _rust_i18n_tkv;
+
+        #typed_keys
     }
 }
 
@@ -448,3 +1083,86 @@ pub fn _minify_key(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn _tr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     parse_macro_input!(input as tr::Tr).into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translations(items: &[(&str, &[(&str, &str)])]) -> HashMap<String, HashMap<String, String>> {
+        items
+            .iter()
+            .map(|(locale, trs)| {
+                let trs = trs
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect();
+                (locale.to_string(), trs)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let no_map = BTreeMap::new();
+        assert_eq!(fallback_chain("en", &[], &no_map), vec!["en"]);
+        assert_eq!(
+            fallback_chain("zh-Hant-CN", &["en".into()], &no_map),
+            vec!["zh-Hant-CN", "zh-Hant", "zh", "en"]
+        );
+
+        let fallback_map = BTreeMap::from([
+            ("zh-HK".into(), vec!["zh-TW".into(), "zh-Hant".into()]),
+            ("pt".into(), vec!["es".into(), "en".into()]),
+        ]);
+        assert_eq!(
+            fallback_chain("zh-HK", &["en".into()], &fallback_map),
+            vec!["zh-HK", "zh-TW", "zh-Hant", "zh", "en"]
+        );
+        assert_eq!(
+            fallback_chain("pt-BR", &["en".into()], &fallback_map),
+            vec!["pt-BR", "pt", "es", "en"]
+        );
+    }
+
+    #[test]
+    fn test_validate_links() {
+        let trs = translations(&[
+            (
+                "en",
+                &[
+                    ("brand.name", "Rust I18n"),
+                    ("welcome", "Welcome to @:brand.name"),
+                    ("nested", "@:welcome, @:{brand.name}!"),
+                    ("unknown", "@:unknown.key"),
+                ],
+            ),
+            ("zh-CN", &[("welcome", "欢迎使用 @:brand.name")]),
+        ]);
+        assert!(validate_links(&trs, &["en".into()], &BTreeMap::new()).is_ok());
+
+        let trs = translations(&[
+            (
+                "en",
+                &[("a", "@:b"), ("b", "@:c."), ("c", "@:a"), ("d", "@:d")],
+            ),
+            ("fr", &[("a", "A")]),
+        ]);
+        let err = validate_links(&trs, &[], &BTreeMap::new()).unwrap_err();
+        let messages = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                "Cyclic linked messages in locale `en`: `a` -> `b` -> `c` -> `a`",
+                "Cyclic linked messages in locale `en`: `d` -> `d`",
+            ]
+        );
+
+        // The cycle is only in the fallback chain of `fr`
+        let trs = translations(&[("en", &[("a", "@:b"), ("b", "B")]), ("fr", &[("b", "@:a")])]);
+        let err = validate_links(&trs, &["en".into()], &BTreeMap::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cyclic linked messages in locale `fr`: `a` -> `b` -> `a`"
+        );
+    }
+}
@@ -1,9 +1,15 @@
+use crate::translations::{self, Translations};
 use quote::{quote, ToTokens};
 use rust_i18n_support::{
-    MinifyKey, DEFAULT_MINIFY_KEY_LEN, DEFAULT_MINIFY_KEY_PREFIX, DEFAULT_MINIFY_KEY_THRESH,
+    extract_placeholders, find_links, is_value_style, IcuMessage, ListStyle, MinifyKey,
+    PluralCategory, PluralRuleType, DATETIME_STYLES, DEFAULT_MINIFY_KEY_LEN,
+    DEFAULT_MINIFY_KEY_PREFIX, DEFAULT_MINIFY_KEY_THRESH, MESSAGE_FORMAT_DEFAULT,
+    MESSAGE_FORMAT_ICU,
 };
+use std::collections::BTreeSet;
 use syn::{parse::discouraged::Speculative, token::Brace, Expr, Ident, LitStr, Token};
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Default)]
 pub enum Value {
     #[default]
@@ -263,10 +269,17 @@ pub(crate) struct Tr {
     pub msg: Messsage,
     pub args: Arguments,
     pub locale: Option<Value>,
+    pub default: Option<Value>,
     pub minify_key: bool,
     pub minify_key_len: usize,
     pub minify_key_prefix: String,
     pub minify_key_thresh: usize,
+    pub message_format: String,
+    pub check_placeholders: bool,
+    pub strict: bool,
+    pub locales_path: Option<String>,
+    pub default_locale: Option<String>,
+    pub locale_from_env: bool,
 }
 
 impl Tr {
@@ -275,10 +288,17 @@ impl Tr {
             msg: Messsage::default(),
             args: Arguments::default(),
             locale: None,
+            default: None,
             minify_key: false,
             minify_key_len: DEFAULT_MINIFY_KEY_LEN,
             minify_key_prefix: DEFAULT_MINIFY_KEY_PREFIX.into(),
             minify_key_thresh: DEFAULT_MINIFY_KEY_THRESH,
+            message_format: MESSAGE_FORMAT_DEFAULT.into(),
+            check_placeholders: false,
+            strict: false,
+            locales_path: None,
+            default_locale: None,
+            locale_from_env: false,
         }
     }
 
@@ -339,12 +359,59 @@ impl Tr {
         ))
     }
 
+    fn parse_message_format(value: &Value) -> syn::parse::Result<String> {
+        if let Value::Expr(Expr::Lit(expr_lit)) = value {
+            if let syn::Lit::Str(lit_str) = &expr_lit.lit {
+                return Ok(lit_str.value());
+            }
+        }
+        Err(syn::Error::new_spanned(
+            value,
+            "`_message_format` Expected a string literal",
+        ))
+    }
+
+    fn parse_bool(value: &Value, name: &str) -> syn::parse::Result<bool> {
+        if let Value::Expr(Expr::Lit(expr_lit)) = value {
+            if let syn::Lit::Bool(lit_bool) = &expr_lit.lit {
+                return Ok(lit_bool.value);
+            }
+        }
+        Err(syn::Error::new_spanned(
+            value,
+            format!("`{}` Expected a boolean literal", name),
+        ))
+    }
+
+    fn parse_string(value: &Value, name: &str) -> syn::parse::Result<String> {
+        if let Value::Expr(Expr::Lit(expr_lit)) = value {
+            if let syn::Lit::Str(lit_str) = &expr_lit.lit {
+                return Ok(lit_str.value());
+            }
+        }
+        Err(syn::Error::new_spanned(
+            value,
+            format!("`{}` Expected a string literal", name),
+        ))
+    }
+
+    fn is_string_literal(value: &Value) -> bool {
+        matches!(value, Value::Expr(Expr::Lit(expr_lit)) if matches!(expr_lit.lit, syn::Lit::Str(_)))
+    }
+
     fn filter_arguments(&mut self) -> syn::parse::Result<()> {
+        // The `strict = false` argument opts out the `strict` option of `i18n!` for a call,
+        // it's only reserved with the `strict` option, which is passed after the arguments of the call.
+        let mut strict = None;
         for arg in self.args.iter() {
             match arg.name.as_str() {
                 "locale" => {
                     self.locale = Some(arg.value.clone());
                 }
+                // Only a string literal is the default text, other values fill a `%{default}` placeholder
+                "default" if Self::is_string_literal(&arg.value) => {
+                    self.default = Some(arg.value.clone());
+                }
                 "_minify_key" => {
                     self.minify_key = Self::parse_minify_key(&arg.value)?;
                 }
@@ -357,24 +424,159 @@ impl Tr {
                 "_minify_key_thresh" => {
                     self.minify_key_thresh = Self::parse_minify_key_thresh(&arg.value)?;
                 }
+                "_message_format" => {
+                    self.message_format = Self::parse_message_format(&arg.value)?;
+                }
+                "_check_placeholders" => {
+                    self.check_placeholders = Self::parse_bool(&arg.value, &arg.name)?;
+                }
+                "_strict" => {
+                    self.strict = Self::parse_bool(&arg.value, &arg.name)?;
+                }
+                "strict" => {
+                    strict = Some(arg);
+                }
+                "_locales_path" => {
+                    self.locales_path = Some(Self::parse_string(&arg.value, &arg.name)?);
+                }
+                "_default_locale" => {
+                    self.default_locale = Some(Self::parse_string(&arg.value, &arg.name)?);
+                }
+                "_locale_from_env" => {
+                    self.locale_from_env = Self::parse_bool(&arg.value, &arg.name)?;
+                }
                 _ => {}
             }
         }
 
+        let strict = match strict {
+            Some(arg) if self.strict => Some(Self::parse_bool(&arg.value, &arg.name)?),
+            _ => None,
+        };
         self.args.as_mut().retain(|v| {
+            match v.name.as_str() {
+                "default" => return !Self::is_string_literal(&v.value),
+                "strict" => return strict.is_none(),
+                _ => {}
+            }
             ![
                 "locale",
                 "_minify_key",
                 "_minify_key_len",
                 "_minify_key_prefix",
                 "_minify_key_thresh",
+                "_message_format",
+                "_check_placeholders",
+                "_strict",
+                "_locales_path",
+                "_default_locale",
+                "_locale_from_env",
             ]
             .contains(&v.name.as_str())
         });
+        self.strict &= strict.unwrap_or(true);
+
+        Ok(())
+    }
+
+    /// Check the arguments against the placeholders of the translations in all locales.
+    ///
+    /// Only the literal messages can be checked, and the messages without any translation are skipped.
+    fn check_placeholders(&self) -> syn::parse::Result<()> {
+        let (Some(locales_path), Some((msg_val, msg_key))) =
+            (&self.locales_path, self.literal_key())
+        else {
+            return Ok(());
+        };
+
+        let translations = translations::get(locales_path);
+        let keys = self.args.keys();
+        let mismatches =
+            mismatched_placeholders(&translations, &msg_key, &keys, &self.message_format);
+        if mismatches.is_empty() {
+            return Ok(());
+        }
+        Err(syn::Error::new_spanned(
+            &self.msg.val,
+            format!(
+                "The arguments of `{}` do not match the placeholders of the translations:\n{}",
+                msg_val,
+                mismatches.join("\n")
+            ),
+        ))
+    }
 
+    /// Check the date and time styles, such as `value : {date_short}`, are used with the `datetime` feature.
+    fn check_datetime_styles(&self) -> syn::parse::Result<()> {
+        if cfg!(feature = "datetime") {
+            return Ok(());
+        }
+        for arg in self.args.iter() {
+            if let Some(style) = arg
+                .specifiers
+                .as_deref()
+                .filter(|style| DATETIME_STYLES.contains(style))
+            {
+                return Err(syn::Error::new_spanned(
+                    &arg.value,
+                    format!(
+                        "The `{}` style needs the `datetime` feature of rust-i18n",
+                        style
+                    ),
+                ));
+            }
+        }
         Ok(())
     }
 
+    /// Check the literal message has a translation in the default locale.
+    fn check_key_exists(&self) -> syn::parse::Result<()> {
+        let (Some(locales_path), Some(default_locale), Some((msg_val, msg_key))) =
+            (&self.locales_path, &self.default_locale, self.literal_key())
+        else {
+            return Ok(());
+        };
+
+        let translations = translations::get(locales_path);
+        let exists = translations.get(default_locale).is_some_and(|trs| {
+            lookup_keys(&msg_key)
+                .iter()
+                .any(|key| trs.contains_key(key))
+        });
+        if exists {
+            return Ok(());
+        }
+        let key = if msg_key == msg_val {
+            format!("`{}`", msg_key)
+        } else {
+            format!("`{}` (minified as `{}`)", msg_val, msg_key)
+        };
+        Err(syn::Error::new_spanned(
+            &self.msg.val,
+            format!(
+                "Unknown translation key {} in the default locale `{}`, use `strict = false` to skip the check",
+                key, default_locale
+            ),
+        ))
+    }
+
+    /// Returns the message and the key of a literal message.
+    fn literal_key(&self) -> Option<(String, String)> {
+        let msg_val = self.msg.val.to_string()?;
+        let msg_key = if self.minify_key {
+            MinifyKey::minify_key(
+                &msg_val,
+                self.minify_key_len,
+                self.minify_key_prefix.as_str(),
+                self.minify_key_thresh,
+            )
+            .to_string()
+        } else {
+            msg_val.clone()
+        };
+        Some((msg_val, msg_key))
+    }
+
     #[cfg(feature = "log-miss-tr")]
     fn log_missing() -> proc_macro2::TokenStream {
         quote! {
@@ -387,6 +589,26 @@ impl Tr {
         quote! {}
     }
 
+    #[cfg(feature = "tracing")]
+    fn trace_missing() -> proc_macro2::TokenStream {
+        quote! {
+            rust_i18n::tracing::warn!(
+                target: "rust-i18n",
+                locale = locale,
+                key = %msg_key,
+                fallback_chain = ?crate::_rust_i18n_fallback_chain(locale),
+                file = file!(),
+                line = line!(),
+                "missing translation"
+            );
+        }
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    fn trace_missing() -> proc_macro2::TokenStream {
+        quote! {}
+    }
+
     fn into_token_stream(self) -> proc_macro2::TokenStream {
         let (msg_key, msg_val) = if self.minify_key && self.msg.val.is_expr_lit_str() {
             let msg_val = self.msg.val.to_string().unwrap();
@@ -411,35 +633,125 @@ impl Tr {
             let msg_key = quote! { &msg_val };
             (msg_key, msg_val)
         };
-        let locale = self.locale.map_or_else(
-            || quote! { &rust_i18n::locale() },
-            |locale| quote! { #locale },
-        );
+        let locale = match self.locale {
+            Some(locale) => quote! { &rust_i18n::canonicalize_locale(#locale) },
+            // The backend sets the locale from the environment variables when it's initialized
+            None if self.locale_from_env => {
+                quote! { &{ crate::_rust_i18n_init_backend(); rust_i18n::locale() } }
+            }
+            None => quote! { &rust_i18n::locale() },
+        };
         let keys: Vec<_> = self.args.keys().iter().map(|v| quote! { #v }).collect();
+        // The number, date and list styles, such as `value : {number}`, are formatted with the locale of the translation
+        let formats: Vec<_> = self
+            .args
+            .iter()
+            .map(|v| match &v.specifiers {
+                Some(s) if is_value_style(s) => s.as_str(),
+                _ => "",
+            })
+            .collect();
         let values: Vec<_> = self
             .args
             .as_ref()
             .iter()
-            .map(|v| {
+            .zip(&formats)
+            .map(|(v, format)| {
                 let value = &v.value;
-                let sepecifiers = v
-                    .specifiers
-                    .as_ref()
-                    .map_or("{}".to_owned(), |s| format!("{{{}}}", s));
+                // The items of a list are passed in `lists`
+                if ListStyle::parse(format).is_some() {
+                    return quote! { String::new() };
+                }
+                // The dates and times are checked by their type, such as the dates of `chrono` and `time`
+                if DATETIME_STYLES.contains(format) {
+                    return quote! { rust_i18n::DateTimeValue::to_iso8601(&(#value)) };
+                }
+                let sepecifiers = match &v.specifiers {
+                    Some(s) if format.is_empty() => format!("{{{}}}", s),
+                    _ => "{}".to_owned(),
+                };
                 quote! { format!(#sepecifiers, #value) }
             })
             .collect();
-        let logging = Self::log_missing();
-        if self.args.is_empty() {
+        // The items of the lists are taken by reference, and formatted with the locale of the translation
+        let lists: Vec<_> = self
+            .args
+            .as_ref()
+            .iter()
+            .zip(&formats)
+            .enumerate()
+            .filter(|(_, (_, format))| ListStyle::parse(format).is_some())
+            .map(|(index, (v, _))| {
+                let value = &v.value;
+                quote! { (#index, { use rust_i18n::_ListItems as _; (#value)._list_items() }) }
+            })
+            .collect();
+        let plural = [
+            ("ordinal", quote! { rust_i18n::PluralRuleType::Ordinal }),
+            ("count", quote! { rust_i18n::PluralRuleType::Cardinal }),
+        ]
+        .into_iter()
+        .find_map(|(name, rule_type)| {
+            let index = self.args.iter().position(|v| v.name == name)?;
+            Some((index, rule_type))
+        });
+        let translate = match &plural {
+            Some((index, rule_type)) => {
+                quote! { crate::_rust_i18n_try_translate_plural_in(locale, &msg_key, #rule_type, &values[#index]) }
+            }
+            None => quote! { crate::_rust_i18n_try_translate_in(locale, &msg_key) },
+        };
+        let log_missing = Self::log_missing();
+        let trace_missing = Self::trace_missing();
+        let logging = quote! { #log_missing #trace_missing };
+        // The `default` argument replaces the message when the translation is missing
+        let missing_val = match &self.default {
+            Some(default) => quote! { #default },
+            None => quote! { msg_val },
+        };
+        // The key is taken before `msg_val` is converted, as it may borrow `msg_val`
+        let report = quote! {
+            let missing_key = rust_i18n::_missing_key(&msg_key);
+            let msg_val = rust_i18n::CowStr::from(#missing_val).into_inner();
+            if let Some(missing_key) = missing_key {
+                rust_i18n::_report_missing(locale, &missing_key, &msg_val, file!(), line!());
+            }
+        };
+        if self.message_format == MESSAGE_FORMAT_ICU {
+            // ICU messages are always formatted, for the quoting and the `select` arguments without values.
+            quote! {
+                {
+                    let msg_val = #msg_val;
+                    let msg_key = #msg_key;
+                    let keys = &[#(#keys),*];
+                    let locale: &str = &#locale;
+                    let values = &[#(#values),*];
+                    let lists: &[(usize, Vec<String>)] = &[#(#lists),*];
+                    let formats: &[&str] = &[#(#formats),*];
+                    // The translation is formatted with the locale that it's found in, for the values and the plural rules
+                    if let Some((translated, resolved_locale)) = #translate {
+                        let formatted = rust_i18n::_format_values(resolved_locale, values, formats, lists);
+                        std::borrow::Cow::from(rust_i18n::_format_icu_translation(resolved_locale, &translated, keys, &formatted))
+                    } else {
+                        #logging
+                        #report
+                        let formatted = rust_i18n::_format_values(locale, values, formats, lists);
+                        std::borrow::Cow::from(rust_i18n::format_icu_message(locale, &msg_val, keys, &formatted))
+                    }
+                }
+            }
+        } else if self.args.is_empty() {
             quote! {
                 {
                     let msg_val = #msg_val;
                     let msg_key = #msg_key;
-                    if let Some(translated) = crate::_rust_i18n_try_translate(#locale, &msg_key) {
-                        translated.into()
+                    let locale: &str = &#locale;
+                    if let Some(translated) = crate::_rust_i18n_try_translate(locale, &msg_key) {
+                        rust_i18n::_unescape_patterns(translated)
                     } else {
                         #logging
-                        rust_i18n::CowStr::from(msg_val).into_inner()
+                        #report
+                        rust_i18n::_unescape_patterns(msg_val)
                     }
                 }
             }
@@ -450,13 +762,17 @@ impl Tr {
                     let msg_key = #msg_key;
                     let keys = &[#(#keys),*];
                     let values = &[#(#values),*];
+                    let lists: &[(usize, Vec<String>)] = &[#(#lists),*];
+                    let formats: &[&str] = &[#(#formats),*];
+                    let locale: &str = &#locale;
                     {
-                    if let Some(translated) = crate::_rust_i18n_try_translate(#locale, &msg_key) {
-                        let replaced = rust_i18n::replace_patterns(&translated, keys, values);
+                    if let Some((translated, resolved_locale)) = #translate {
+                        let replaced = rust_i18n::_replace_patterns_in(resolved_locale, &translated, keys, values, formats, lists);
                         std::borrow::Cow::from(replaced)
                     } else {
                         #logging
-                        let replaced = rust_i18n::replace_patterns(rust_i18n::CowStr::from(msg_val).as_str(), keys, values);
+                        #report
+                        let replaced = rust_i18n::_replace_patterns_in(locale, &msg_val, keys, values, formats, lists);
                         std::borrow::Cow::from(replaced)
                     }
                 }
@@ -489,11 +805,112 @@ impl syn::parse::Parse for Tr {
         };
 
         result.filter_arguments()?;
+        result.check_datetime_styles()?;
+        // The key of a call with the `default` text is expected to be missing until it's translated
+        if result.strict && result.default.is_none() {
+            result.check_key_exists()?;
+        }
+        if result.check_placeholders {
+            result.check_placeholders()?;
+        }
 
         Ok(result)
     }
 }
 
+/// The keys that `t!` may look up for the key, including the plural forms.
+fn lookup_keys(key: &str) -> Vec<String> {
+    let plural_keys = [PluralRuleType::Cardinal, PluralRuleType::Ordinal]
+        .into_iter()
+        .flat_map(|rule_type| {
+            let prefix = rule_type.key_prefix();
+            PluralCategory::ALL
+                .iter()
+                .map(move |category| format!("{}.{}{}", key, prefix, category))
+        });
+    std::iter::once(key.to_string())
+        .chain(plural_keys)
+        .collect()
+}
+
+/// Compare the argument names with the placeholders of the translations of the key, including the plural forms.
+///
+/// Returns a description for each locale with missing or unused arguments, sorted by locale.
+/// The `@:key` links are resolved in the same locale, and the unused arguments are not checked for unresolved links.
+/// The `count` and `ordinal` arguments select the plural form, so they are allowed to be unused.
+fn mismatched_placeholders(
+    translations: &Translations,
+    key: &str,
+    args: &[String],
+    message_format: &str,
+) -> Vec<String> {
+    let keys = lookup_keys(key);
+    let mut locales = translations.keys().collect::<Vec<_>>();
+    locales.sort();
+    locales
+        .into_iter()
+        .filter_map(|locale| {
+            let trs = &translations[locale];
+            let values = keys
+                .iter()
+                .filter_map(|key| trs.get(key))
+                .map(|value| translations::resolve_links(trs, value))
+                .collect::<Vec<_>>();
+            if values.is_empty() {
+                return None;
+            }
+            // The placeholders of the links resolved with the fallback locales are unknown
+            let has_links = values.iter().any(|value| !find_links(value).is_empty());
+
+            let placeholders = values
+                .iter()
+                .flat_map(|value| {
+                    if message_format == MESSAGE_FORMAT_ICU {
+                        // The syntax errors are reported by `i18n!`
+                        IcuMessage::parse(value)
+                            .map(|message| {
+                                message
+                                    .arguments()
+                                    .into_iter()
+                                    .map(String::from)
+                                    .collect::<Vec<_>>()
+                            })
+                            .unwrap_or_default()
+                    } else {
+                        extract_placeholders(value)
+                            .into_iter()
+                            .map(String::from)
+                            .collect()
+                    }
+                })
+                .collect::<BTreeSet<_>>();
+            let args = args.iter().cloned().collect::<BTreeSet<_>>();
+
+            let missing = placeholders
+                .difference(&args)
+                .map(|name| format!("`{}`", name))
+                .collect::<Vec<_>>();
+            let unused = args
+                .difference(&placeholders)
+                .filter(|name| !has_links && !["count", "ordinal"].contains(&name.as_str()))
+                .map(|name| format!("`{}`", name))
+                .collect::<Vec<_>>();
+
+            let mut problems = vec![];
+            if !missing.is_empty() {
+                problems.push(format!("missing {}", missing.join(", ")));
+            }
+            if !unused.is_empty() {
+                problems.push(format!("unused {}", unused.join(", ")));
+            }
+            if problems.is_empty() {
+                return None;
+            }
+            Some(format!("  - {}: {}", locale, problems.join("; ")))
+        })
+        .collect()
+}
+
 impl From<Tr> for proc_macro::TokenStream {
     fn from(args: Tr) -> Self {
         args.into_token_stream().into()
@@ -505,3 +922,130 @@ impl From<Tr> for proc_macro2::TokenStream {
         args.into_token_stream()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translations(items: &[(&str, &[(&str, &str)])]) -> Translations {
+        items
+            .iter()
+            .map(|(locale, trs)| {
+                let trs = trs
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect();
+                (locale.to_string(), trs)
+            })
+            .collect()
+    }
+
+    fn args(names: &[&str]) -> Vec<String> {
+        names.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_mismatched_placeholders() {
+        let trs = translations(&[
+            ("en", &[("hello", "Hello, %{username}!")]),
+            ("zh-CN", &[("hello", "你好，%{name}！")]),
+            ("fr", &[("other", "Autre")]),
+            ("ja", &[("hello", "%{name}さん、こんにちは！%%{escaped}")]),
+        ]);
+
+        assert_eq!(
+            mismatched_placeholders(&trs, "hello", &args(&["name"]), MESSAGE_FORMAT_DEFAULT),
+            vec!["  - en: missing `username`; unused `name`"]
+        );
+        assert_eq!(
+            mismatched_placeholders(&trs, "hello", &args(&[]), MESSAGE_FORMAT_DEFAULT),
+            vec![
+                "  - en: missing `username`",
+                "  - ja: missing `name`",
+                "  - zh-CN: missing `name`"
+            ]
+        );
+        // Without any translation
+        assert!(
+            mismatched_placeholders(&trs, "unknown", &args(&["name"]), MESSAGE_FORMAT_DEFAULT)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_mismatched_placeholders_with_links() {
+        let trs = translations(&[
+            (
+                "en",
+                &[
+                    ("greeting", "Hello, %{name}"),
+                    ("welcome", "@:greeting, welcome to %{app}"),
+                ],
+            ),
+            ("zh-CN", &[("welcome", "@:greeting，欢迎使用 %{app}")]),
+        ]);
+
+        assert!(mismatched_placeholders(
+            &trs,
+            "welcome",
+            &args(&["name", "app"]),
+            MESSAGE_FORMAT_DEFAULT
+        )
+        .is_empty());
+        assert_eq!(
+            mismatched_placeholders(&trs, "welcome", &args(&["app"]), MESSAGE_FORMAT_DEFAULT),
+            vec!["  - en: missing `name`"]
+        );
+    }
+
+    #[test]
+    fn test_mismatched_placeholders_with_plural() {
+        let trs = translations(&[(
+            "en",
+            &[
+                ("inbox.one", "One message from %{name}"),
+                ("inbox.other", "%{count} messages from %{name}"),
+                ("rank.ordinal.other", "%{ordinal}th"),
+            ],
+        )]);
+
+        assert!(mismatched_placeholders(
+            &trs,
+            "inbox",
+            &args(&["count", "name"]),
+            MESSAGE_FORMAT_DEFAULT
+        )
+        .is_empty());
+        assert_eq!(
+            mismatched_placeholders(&trs, "inbox", &args(&["count"]), MESSAGE_FORMAT_DEFAULT),
+            vec!["  - en: missing `name`"]
+        );
+        assert!(
+            mismatched_placeholders(&trs, "rank", &args(&["ordinal"]), MESSAGE_FORMAT_DEFAULT)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_mismatched_placeholders_with_icu() {
+        let trs = translations(&[(
+            "en",
+            &[(
+                "files",
+                "{name} has {count, plural, one {# file} other {# files}}",
+            )],
+        )]);
+
+        assert!(mismatched_placeholders(
+            &trs,
+            "files",
+            &args(&["name", "count"]),
+            MESSAGE_FORMAT_ICU
+        )
+        .is_empty());
+        assert_eq!(
+            mismatched_placeholders(&trs, "files", &args(&["user"]), MESSAGE_FORMAT_ICU),
+            vec!["  - en: missing `count`, `name`; unused `user`"]
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use once_cell::sync::Lazy;
+use rust_i18n_support::{load_locales, locale_files, replace_links, MAX_LINK_DEPTH};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+pub type Translations = HashMap<String, HashMap<String, String>>;
+
+/// The locale files with their modified time and size, to detect the changes of the locale files.
+type Fingerprint = Vec<(PathBuf, Option<SystemTime>, u64)>;
+
+/// The translations with the fingerprint of the locale files that they are loaded from,
+/// and the time that the fingerprint is checked.
+struct Loaded {
+    fingerprint: Fingerprint,
+    checked_at: Instant,
+    translations: Arc<Translations>,
+}
+
+/// The translations loaded by `i18n!`, keyed by the absolute locales path.
+///
+/// The `_tr!` macro runs in the same compiler process, so it can inspect the translations
+/// without parsing the locale files again for each call site. The compiler process may be kept
+/// between builds, such as by rust-analyzer, so the translations are reloaded when the locale files change.
+static TRANSLATIONS: Lazy<Mutex<HashMap<String, Loaded>>> = Lazy::new(Default::default);
+
+/// The interval to check the locale files again, so the files are not walked for each `t!` call site.
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+fn fingerprint(locales_path: &str) -> Fingerprint {
+    let mut fingerprint: Fingerprint = locale_files(locales_path)
+        .into_iter()
+        .map(|path| {
+            let metadata = std::fs::metadata(&path).ok();
+            let modified = metadata.as_ref().and_then(|m| m.modified().ok());
+            let len = metadata.map_or(0, |m| m.len());
+            (path, modified, len)
+        })
+        .collect();
+    fingerprint.sort();
+    fingerprint
+}
+
+/// Store the translations of the locales path, replacing the previous ones.
+pub fn store(locales_path: &str, translations: Translations) {
+    TRANSLATIONS.lock().unwrap().insert(
+        locales_path.to_string(),
+        Loaded {
+            fingerprint: fingerprint(locales_path),
+            checked_at: Instant::now(),
+            translations: Arc::new(translations),
+        },
+    );
+}
+
+/// Get the translations of the locales path, and load them if they are not stored yet or the locale files have changed.
+///
+/// The locale files are checked at most once per [`CHECK_INTERVAL`].
+pub fn get(locales_path: &str) -> Arc<Translations> {
+    get_with_interval(locales_path, CHECK_INTERVAL)
+}
+
+fn get_with_interval(locales_path: &str, interval: Duration) -> Arc<Translations> {
+    let mut cache = TRANSLATIONS.lock().unwrap();
+    if let Some(loaded) = cache.get_mut(locales_path) {
+        if loaded.checked_at.elapsed() < interval {
+            return loaded.translations.clone();
+        }
+        let fingerprint = fingerprint(locales_path);
+        if loaded.fingerprint == fingerprint {
+            loaded.checked_at = Instant::now();
+            return loaded.translations.clone();
+        }
+    }
+
+    let translations = Arc::new(load_locales(locales_path, |_| false));
+    cache.insert(
+        locales_path.to_string(),
+        Loaded {
+            fingerprint: fingerprint(locales_path),
+            checked_at: Instant::now(),
+            translations: translations.clone(),
+        },
+    );
+    translations
+}
+
+/// Resolve the `@:key` links of the value with the translations of the same locale.
+///
+/// The links to the keys that only exist in the fallback locales are kept as-is.
+pub fn resolve_links(trs: &HashMap<String, String>, value: &str) -> String {
+    let mut resolved = HashMap::new();
+    resolve_links_with(trs, value, 0, &mut resolved)
+}
+
+/// Resolve the links with the resolved keys memoized, so a key linked many times is resolved once.
+fn resolve_links_with<'a>(
+    trs: &'a HashMap<String, String>,
+    value: &str,
+    depth: usize,
+    resolved: &mut HashMap<&'a str, Option<String>>,
+) -> String {
+    if depth >= MAX_LINK_DEPTH {
+        return value.to_string();
+    }
+    replace_links(value, |key| {
+        let (key, linked) = trs.get_key_value(key)?;
+        if let Some(cached) = resolved.get(key.as_str()) {
+            return cached.clone();
+        }
+        let linked = Some(resolve_links_with(trs, linked, depth + 1, resolved));
+        resolved.insert(key, linked.clone());
+        linked
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_links() {
+        let trs: HashMap<String, String> = [
+            ("brand", "Rust I18n"),
+            ("welcome", "Welcome to @:brand, @@:brand is a link."),
+            ("unknown", "Hello @:unknown.key"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        assert_eq!(
+            resolve_links(&trs, "@:welcome"),
+            "Welcome to Rust I18n, @:brand is a link."
+        );
+        assert_eq!(resolve_links(&trs, "@:unknown"), "Hello @:unknown.key");
+    }
+
+    #[test]
+    fn test_resolve_repeated_links() {
+        // Each level links to the next one 4 times, which is 4^15 lookups without memoizing
+        let mut trs: HashMap<String, String> = (0..MAX_LINK_DEPTH - 1)
+            .map(|i| {
+                (
+                    format!("l{}", i),
+                    format!("@:l{0}@:l{0}@:l{0}@:l{0}", i + 1),
+                )
+            })
+            .collect();
+        trs.insert(format!("l{}", MAX_LINK_DEPTH - 1), String::new());
+        assert_eq!(resolve_links(&trs, "Done: @:l0!"), "Done: !");
+    }
+
+    #[test]
+    fn test_get_reloads_changed_locale_files() {
+        let dir =
+            std::env::temp_dir().join(format!("rust-i18n-translations-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("app.yml");
+        let locales_path = dir.display().to_string();
+
+        std::fs::write(&file, "_version: 2\nhello:\n  en: Hello\n").unwrap();
+        store(&locales_path, load_locales(&locales_path, |_| false));
+        assert_eq!(get(&locales_path)["en"]["hello"], "Hello");
+
+        // The files are not checked again until the interval has passed
+        std::fs::write(&file, "_version: 2\nhello:\n  en: Hello, %{name}\n").unwrap();
+        let hour = Duration::from_secs(3600);
+        assert_eq!(
+            get_with_interval(&locales_path, hour)["en"]["hello"],
+            "Hello"
+        );
+        assert_eq!(
+            get_with_interval(&locales_path, Duration::ZERO)["en"]["hello"],
+            "Hello, %{name}"
+        );
+
+        std::fs::write(dir.join("extra.yml"), "_version: 2\nbye:\n  en: Bye\n").unwrap();
+        assert_eq!(
+            get_with_interval(&locales_path, Duration::ZERO)["en"]["bye"],
+            "Bye"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,282 @@
+use crate::translations;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use rust_i18n_support::{
+    extract_placeholders, IcuMessage, PluralCategory, PluralRuleType, MESSAGE_FORMAT_ICU,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// A node of the translation keys, split by `.`.
+#[derive(Default)]
+struct Node<'a> {
+    value: Option<&'a str>,
+    children: BTreeMap<&'a str, Node<'a>>,
+}
+
+impl<'a> Node<'a> {
+    fn insert(&mut self, key: &'a str, value: &'a str) {
+        let node = key
+            .split('.')
+            .fold(self, |node, part| node.children.entry(part).or_default());
+        node.value = Some(value);
+    }
+
+    /// Whether the children are the plural forms of a key, such as `one` and `other`.
+    fn is_plural(&self) -> bool {
+        self.value.is_none()
+            && self.children.contains_key(PluralCategory::Other.as_str())
+            && self.children.iter().all(|(name, child)| {
+                child.children.is_empty()
+                    && PluralCategory::ALL
+                        .iter()
+                        .any(|category| category.as_str() == *name)
+            })
+    }
+
+    /// The values of the plural forms in the CLDR order.
+    fn plural_values(&self) -> Vec<&'a str> {
+        PluralCategory::ALL
+            .iter()
+            .filter_map(|category| self.children.get(category.as_str())?.value)
+            .collect()
+    }
+}
+
+/// The generator of the typed key module.
+pub struct TypedKeys<'a> {
+    pub module: &'a str,
+    pub minify_key: bool,
+    pub message_format: &'a str,
+}
+
+impl TypedKeys<'_> {
+    /// Generate a module with a function for each key of the translations, such as `tr::views::message::title(name)`
+    /// for `views.message.title: "Hello, %{name}"`.
+    ///
+    /// The functions take the placeholders of the translations as parameters, and translate with the current locale.
+    /// The plural forms of a key are generated as one function with the `count` (or `ordinal`) parameter.
+    pub fn generate(&self, translations: &HashMap<String, String>) -> TokenStream {
+        // The placeholders of the linked messages are also the parameters
+        let translations = translations
+            .iter()
+            .map(|(key, value)| (key, translations::resolve_links(translations, value)))
+            .collect::<Vec<_>>();
+        let mut root = Node::default();
+        translations
+            .iter()
+            .for_each(|(key, value)| root.insert(key, value));
+
+        let module = Ident::new(self.module, Span::call_site());
+        let items = self.generate_items(root.children.iter(), "");
+        quote! {
+            /// Typed accessors of the translation keys.
+            #[allow(dead_code, missing_docs, non_snake_case)]
+            pub mod #module {
+                #items
+            }
+        }
+    }
+
+    fn generate_items<'n, 'a: 'n>(
+        &self,
+        children: impl Iterator<Item = (&'n &'a str, &'n Node<'a>)>,
+        prefix: &str,
+    ) -> TokenStream {
+        // Functions and modules are in different namespaces, so `a` and `a.b` can both be generated.
+        let mut fns = HashSet::new();
+        let mut mods = HashSet::new();
+        let mut items = TokenStream::new();
+
+        for (name, node) in children {
+            let Some(ident) = to_ident(name) else {
+                continue;
+            };
+            let key = format!("{}{}", prefix, name);
+
+            let ordinal_name = PluralRuleType::Ordinal.key_prefix().trim_end_matches('.');
+            let ordinal = node
+                .children
+                .get(ordinal_name)
+                .filter(|ordinal| node.value.is_none() && ordinal.is_plural());
+            let function = if node.is_plural() {
+                Some((node.plural_values(), "count"))
+            } else if let Some(value) = node.value {
+                Some((vec![value], ""))
+            } else {
+                ordinal.map(|ordinal| (ordinal.plural_values(), "ordinal"))
+            };
+            if let Some((values, selector)) = function {
+                if fns.insert(ident.to_string()) {
+                    items.extend(self.generate_fn(&ident, &key, &values, selector));
+                }
+            }
+            if node.is_plural() {
+                continue;
+            }
+
+            let children = node
+                .children
+                .iter()
+                .filter(|(name, _)| ordinal.is_none() || **name != ordinal_name)
+                .collect::<Vec<_>>();
+            if children.is_empty() || !mods.insert(ident.to_string()) {
+                continue;
+            }
+            let sub_items = self.generate_items(children.into_iter(), &format!("{}.", key));
+            items.extend(quote! {
+                pub mod #ident {
+                    #sub_items
+                }
+            });
+        }
+
+        items
+    }
+
+    /// Generate the function of the key, `selector` is the `count` or `ordinal` parameter of the plural forms.
+    fn generate_fn(
+        &self,
+        ident: &Ident,
+        key: &str,
+        values: &[&str],
+        selector: &str,
+    ) -> TokenStream {
+        let mut names = vec![];
+        if !selector.is_empty() {
+            names.push(selector.to_string());
+        }
+        values
+            .iter()
+            .flat_map(|value| placeholders(value, self.message_format))
+            .for_each(|name| {
+                // The `locale` and `strict` are the options of `t!`, not placeholders
+                if !names.contains(&name) && !["locale", "strict"].contains(&name.as_str()) {
+                    names.push(name);
+                }
+            });
+
+        let mut params = vec![];
+        let mut args = vec![];
+        let mut used = HashSet::new();
+        for name in &names {
+            let Some(mut param) = to_ident(name) else {
+                continue;
+            };
+            let mut n = 1;
+            while !used.insert(param.to_string()) {
+                n += 1;
+                param = Ident::new(
+                    &format!("{}_{}", param.to_string().trim_start_matches("r#"), n),
+                    Span::call_site(),
+                );
+            }
+            params.push(quote! { #param: impl std::fmt::Display });
+            args.push(quote! { #name = #param });
+        }
+
+        let msg = if self.minify_key {
+            quote! { (#key, #key) }
+        } else {
+            quote! { #key }
+        };
+        let doc = values
+            .iter()
+            .map(|value| format!(" `{}`: {}", key, value))
+            .collect::<Vec<_>>();
+        quote! {
+            #(#[doc = #doc])*
+            #[inline]
+            pub fn #ident(#(#params),*) -> std::borrow::Cow<'static, str> {
+                crate::_rust_i18n_t!(#msg #(, #args)*)
+            }
+        }
+    }
+}
+
+/// Convert a key segment or a placeholder name to a Rust identifier.
+///
+/// The characters other than ASCII letters, digits and `_` are replaced with `_`, and keywords are escaped.
+fn to_ident(name: &str) -> Option<Ident> {
+    let mut ident = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if ident.chars().all(|c| c == '_') {
+        return None;
+    }
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if ["self", "Self", "super", "crate"].contains(&ident.as_str()) {
+        ident.push('_');
+    }
+    match syn::parse_str::<Ident>(&ident) {
+        Ok(ident) => Some(ident),
+        Err(_) => Some(Ident::new_raw(&ident, Span::call_site())),
+    }
+}
+
+/// The names of the placeholders in the message.
+fn placeholders(value: &str, message_format: &str) -> Vec<String> {
+    if message_format == MESSAGE_FORMAT_ICU {
+        IcuMessage::parse(value)
+            .map(|message| message.arguments().into_iter().map(String::from).collect())
+            .unwrap_or_default()
+    } else {
+        extract_placeholders(value)
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ident() {
+        let ident = |name| to_ident(name).map(|ident| ident.to_string());
+        assert_eq!(ident("title"), Some("title".into()));
+        assert_eq!(ident("lorem-ipsum"), Some("lorem_ipsum".into()));
+        assert_eq!(ident("404"), Some("_404".into()));
+        assert_eq!(ident("type"), Some("r#type".into()));
+        assert_eq!(ident("self"), Some("self_".into()));
+        assert_eq!(ident("Hello World!"), Some("Hello_World_".into()));
+        assert_eq!(ident("-"), None);
+        assert_eq!(ident(""), None);
+    }
+
+    #[test]
+    fn test_generate() {
+        let translations = [
+            ("views.message.title", "Hello, %{name}!"),
+            ("views.message", "Message"),
+            ("inbox.one", "One message"),
+            ("inbox.other", "%{count} messages from %{name}"),
+            ("rank.ordinal.one", "%{ordinal}st"),
+            ("rank.ordinal.other", "%{ordinal}th"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let typed_keys = TypedKeys {
+            module: "tr",
+            minify_key: false,
+            message_format: "default",
+        };
+        let code = typed_keys.generate(&translations).to_string();
+
+        assert!(code.contains("pub mod tr"));
+        assert!(code.contains(
+            "pub fn title (name : impl std :: fmt :: Display) -> std :: borrow :: Cow < 'static , str > { crate :: _rust_i18n_t ! (\"views.message.title\" , \"name\" = name) }"
+        ));
+        assert!(code.contains("pub fn message () -> std :: borrow :: Cow < 'static , str > { crate :: _rust_i18n_t ! (\"views.message\") }"));
+        assert!(code.contains(
+            "pub fn inbox (count : impl std :: fmt :: Display , name : impl std :: fmt :: Display)"
+        ));
+        assert!(!code.contains("pub mod inbox"));
+        assert!(code.contains("pub fn rank (ordinal : impl std :: fmt :: Display)"));
+        assert!(!code.contains("pub mod rank"));
+    }
+}
@@ -18,6 +18,17 @@ impl Deref for GuardedStr {
     }
 }
 
+/// A string that was stored in `AtomicStr`, returned by `AtomicStr::swap()`.
+struct ArcedStr(Arc<String>);
+
+impl Deref for ArcedStr {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_str()
+    }
+}
+
 impl AtomicStr {
     /// Create a new `AtomicStr` with the given value.
     pub fn new(value: &str) -> Self {
@@ -35,6 +46,12 @@ impl AtomicStr {
         let arced = Arc::new(src.into());
         self.0.store(arced);
     }
+
+    /// Replaces the value at self with src, and returns the previous value.
+    pub fn swap(&self, src: impl Into<String>) -> impl Deref<Target = str> {
+        let arced = Arc::new(src.into());
+        ArcedStr(self.0.swap(arced))
+    }
 }
 
 impl From<&str> for AtomicStr {
@@ -62,4 +79,12 @@ mod tests {
         let s = AtomicStr::from("hello");
         test_str(&s.as_str());
     }
+
+    #[test]
+    fn test_atomic_str_swap() {
+        let s = AtomicStr::from("hello");
+        let previous = s.swap("world");
+        test_str(&previous);
+        assert_eq!(&*s.as_str(), "world");
+    }
 }
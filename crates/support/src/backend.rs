@@ -1,3 +1,5 @@
+use crate::{canonicalize_locale, pseudo_localize, PSEUDO_LOCALES};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// I18n backend trait
@@ -5,6 +7,9 @@ pub trait Backend: Send + Sync + 'static {
     /// Return the available locales
     fn available_locales(&self) -> Vec<&str>;
     /// Get the translation for the given locale and key
+    ///
+    /// The locale is canonicalized with [`canonicalize_locale`], such as `zh-CN` for `set_locale("zh_cn")`,
+    /// so the translations should be stored with the canonical locales.
     fn translate(&self, locale: &str, key: &str) -> Option<&str>;
 }
 
@@ -43,6 +48,17 @@ where
     }
 }
 
+/// A translation with the locale that it's found in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolved<'a> {
+    /// The translation, with the linked messages resolved, and the placeholders as-is.
+    pub value: Cow<'a, str>,
+    /// The locale of the translation.
+    pub locale: String,
+    /// Whether the locale is a fallback locale, rather than the requested one.
+    pub via_fallback: bool,
+}
+
 /// Simple KeyValue storage backend
 pub struct SimpleBackend {
     /// All translations key is flatten key, like `en.hello.world`
@@ -57,7 +73,7 @@ impl SimpleBackend {
         }
     }
 
-    /// Add more translations for the given locale.
+    /// Add more translations for the given locale, the locale is canonicalized, such as `en_us` -> `en-US`.
     ///
     /// ```no_run
     /// # use std::collections::HashMap;
@@ -74,9 +90,41 @@ impl SimpleBackend {
             .map(|(k, v)| ((*k).into(), (*v).into()))
             .collect::<HashMap<_, _>>();
 
-        let trs = self.translations.entry(locale.into()).or_default();
+        let locale = canonicalize_locale(locale).into_owned();
+        let trs = self.translations.entry(locale).or_default();
         trs.extend(data);
     }
+
+    /// Add the pseudo-locales `en-XA` and `ar-XB`, generated from the translations of `source_locale`
+    /// by [`pseudo_localize`](crate::pseudo_localize).
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use rust_i18n_support::{Backend, SimpleBackend};
+    /// let mut backend = SimpleBackend::new();
+    /// backend.add_translations("en", &HashMap::from([("hello", "Hello")]));
+    /// backend.add_pseudo_locales("en");
+    /// assert_eq!(backend.translate("en-XA", "hello"), Some("[Ĥéļļö ~~]"));
+    /// ```
+    pub fn add_pseudo_locales(&mut self, source_locale: &str) {
+        let Some(source) = self
+            .translations
+            .get(canonicalize_locale(source_locale).as_ref())
+        else {
+            return;
+        };
+        let pseudo = PSEUDO_LOCALES
+            .iter()
+            .map(|locale| {
+                let trs = source
+                    .iter()
+                    .filter_map(|(key, value)| Some((key.clone(), pseudo_localize(locale, value)?)))
+                    .collect::<HashMap<_, _>>();
+                (locale.to_string(), trs)
+            })
+            .collect::<Vec<_>>();
+        self.translations.extend(pseudo);
+    }
 }
 
 impl Backend for SimpleBackend {
@@ -113,6 +161,7 @@ mod tests {
 
     use super::SimpleBackend;
     use super::{Backend, BackendExt};
+    use crate::canonicalize_locale;
 
     #[test]
     fn test_simple_backend() {
@@ -135,6 +184,49 @@ mod tests {
         assert_eq!(backend.available_locales(), vec!["en", "zh-CN"]);
     }
 
+    #[test]
+    fn test_simple_backend_canonical_locales() {
+        let mut backend = SimpleBackend::new();
+        let mut data = HashMap::<&str, &str>::new();
+        data.insert("hello", "Hello");
+        backend.add_translations("en_us", &data);
+
+        let mut data = HashMap::<&str, &str>::new();
+        data.insert("foo", "Foo");
+        backend.add_translations("EN-US", &data);
+
+        assert_eq!(backend.available_locales(), vec!["en-US"]);
+        assert_eq!(backend.translate("en-US", "hello"), Some("Hello"));
+        assert_eq!(backend.translate("en-US", "foo"), Some("Foo"));
+        // The locales of the lookups are canonicalized by `set_locale` and `t!`
+        assert_eq!(backend.translate("en_us", "hello"), None);
+    }
+
+    #[test]
+    fn test_simple_backend_pseudo_locales() {
+        let mut backend = SimpleBackend::new();
+        let mut data = HashMap::<&str, &str>::new();
+        data.insert("hello", "Hello, %{name}");
+        backend.add_translations("en", &data);
+        backend.add_pseudo_locales("fr");
+        assert_eq!(backend.available_locales(), vec!["en"]);
+
+        backend.add_pseudo_locales("en");
+        assert_eq!(backend.available_locales(), vec!["ar-XB", "en", "en-XA"]);
+        assert_eq!(
+            backend.translate("en-XA", "hello"),
+            Some("[Ĥéļļö, %{name} ~~~]")
+        );
+        assert_eq!(
+            backend.translate("ar-XB", "hello"),
+            Some("\u{200F}\u{202E}Hello,\u{202C}\u{200F} %{name}")
+        );
+        assert_eq!(
+            backend.translate(&canonicalize_locale("en_xa"), "hello"),
+            backend.translate("en-XA", "hello")
+        );
+    }
+
     #[test]
     fn test_combined_backend() {
         let mut backend = SimpleBackend::new();
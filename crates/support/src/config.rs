@@ -5,6 +5,7 @@
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::io;
 use std::io::Read;
@@ -21,6 +22,8 @@ pub struct I18nConfig {
     pub load_path: String,
     #[serde(default = "fallback")]
     pub fallback: Vec<String>,
+    #[serde(default = "fallback_map")]
+    pub fallback_map: BTreeMap<String, Vec<String>>,
     #[serde(default = "minify_key")]
     pub minify_key: bool,
     #[serde(default = "minify_key_len")]
@@ -29,6 +32,18 @@ pub struct I18nConfig {
     pub minify_key_prefix: String,
     #[serde(default = "minify_key_thresh")]
     pub minify_key_thresh: usize,
+    #[serde(default = "message_format")]
+    pub message_format: String,
+    #[serde(default = "check_placeholders")]
+    pub check_placeholders: bool,
+    #[serde(default = "strict")]
+    pub strict: bool,
+    #[serde(default = "typed_keys")]
+    pub typed_keys: Option<String>,
+    #[serde(default = "locale_from_env")]
+    pub locale_from_env: bool,
+    #[serde(default = "pseudo_locales")]
+    pub pseudo_locales: bool,
 }
 
 impl Default for I18nConfig {
@@ -38,10 +53,17 @@ impl Default for I18nConfig {
             available_locales: vec!["en".to_string()],
             load_path: "./locales".to_string(),
             fallback: vec![],
+            fallback_map: BTreeMap::new(),
             minify_key: crate::DEFAULT_MINIFY_KEY,
             minify_key_len: crate::DEFAULT_MINIFY_KEY_LEN,
             minify_key_prefix: crate::DEFAULT_MINIFY_KEY_PREFIX.to_string(),
             minify_key_thresh: crate::DEFAULT_MINIFY_KEY_THRESH,
+            message_format: crate::MESSAGE_FORMAT_DEFAULT.to_string(),
+            check_placeholders: false,
+            strict: false,
+            typed_keys: None,
+            locale_from_env: false,
+            pseudo_locales: false,
         }
     }
 }
@@ -66,7 +88,9 @@ impl I18nConfig {
         if !contents.contains("[i18n]") && !contents.contains("[package.metadata.i18n]") {
             return Ok(I18nConfig::default());
         }
-        let contents = contents.replace("[package.metadata.i18n]", "[i18n]");
+        let contents = contents
+            .replace("[package.metadata.i18n]", "[i18n]")
+            .replace("[package.metadata.i18n.", "[i18n.");
         let mut config: MainConfig = toml::from_str(&contents)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
 
@@ -100,6 +124,10 @@ fn fallback() -> Vec<String> {
     I18nConfig::default().fallback
 }
 
+fn fallback_map() -> BTreeMap<String, Vec<String>> {
+    I18nConfig::default().fallback_map
+}
+
 fn minify_key() -> bool {
     I18nConfig::default().minify_key
 }
@@ -116,6 +144,30 @@ fn minify_key_thresh() -> usize {
     I18nConfig::default().minify_key_thresh
 }
 
+fn message_format() -> String {
+    I18nConfig::default().message_format
+}
+
+fn check_placeholders() -> bool {
+    I18nConfig::default().check_placeholders
+}
+
+fn strict() -> bool {
+    I18nConfig::default().strict
+}
+
+fn typed_keys() -> Option<String> {
+    I18nConfig::default().typed_keys
+}
+
+fn locale_from_env() -> bool {
+    I18nConfig::default().locale_from_env
+}
+
+fn pseudo_locales() -> bool {
+    I18nConfig::default().pseudo_locales
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "kebab-case")]
 pub struct MainConfig {
@@ -155,6 +207,13 @@ fn test_parse() {
     assert_eq!(cfg.default_locale, "en");
     assert_eq!(cfg.available_locales, vec!["en", "zh-CN", "de"]);
     assert_eq!(cfg.load_path, "./my-locales");
+    assert_eq!(cfg.message_format, "default");
+    assert!(!cfg.check_placeholders);
+    assert!(!cfg.strict);
+    assert_eq!(cfg.typed_keys, None);
+    assert!(!cfg.locale_from_env);
+    assert!(!cfg.pseudo_locales);
+    assert!(cfg.fallback_map.is_empty());
 
     let contents = "";
     let cfg = I18nConfig::parse(contents).unwrap();
@@ -175,6 +234,13 @@ fn test_parse_with_metadata() {
         minify-key-len = 12
         minify-key-prefix = "T_"
         minify-key-thresh = 16
+        message-format = "icu"
+        check-placeholders = true
+        strict = true
+        typed-keys = "tr"
+        locale-from-env = true
+        pseudo-locales = true
+        fallback-map = { zh-HK = ["zh-TW", "zh-Hant"] }
     "#;
 
     let cfg = I18nConfig::parse(contents).unwrap();
@@ -186,6 +252,38 @@ fn test_parse_with_metadata() {
     assert_eq!(cfg.minify_key_len, 12);
     assert_eq!(cfg.minify_key_prefix, "T_");
     assert_eq!(cfg.minify_key_thresh, 16);
+    assert_eq!(cfg.message_format, "icu");
+    assert!(cfg.check_placeholders);
+    assert!(cfg.strict);
+    assert_eq!(cfg.typed_keys.as_deref(), Some("tr"));
+    assert!(cfg.locale_from_env);
+    assert!(cfg.pseudo_locales);
+    assert_eq!(
+        cfg.fallback_map,
+        BTreeMap::from([("zh-HK".into(), vec!["zh-TW".into(), "zh-Hant".into()])])
+    );
+}
+
+#[test]
+fn test_parse_fallback_map_table() {
+    let contents = r#"
+        [package.metadata.i18n]
+        fallback = ["en"]
+
+        [package.metadata.i18n.fallback-map]
+        zh-HK = ["zh-TW", "zh-Hant"]
+        pt-BR = ["pt-PT"]
+    "#;
+
+    let cfg = I18nConfig::parse(contents).unwrap();
+    assert_eq!(cfg.fallback, vec!["en"]);
+    assert_eq!(
+        cfg.fallback_map,
+        BTreeMap::from([
+            ("pt-BR".into(), vec!["pt-PT".into()]),
+            ("zh-HK".into(), vec!["zh-TW".into(), "zh-Hant".into()]),
+        ])
+    );
 }
 
 #[test]
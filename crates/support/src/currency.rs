@@ -0,0 +1,203 @@
+use crate::locale::find_locale;
+use crate::number::{currency_pattern, minus_sign, parse_decimal, round_decimal};
+use crate::{format_number, NumberStyle};
+
+/// How the currency is displayed by [`format_currency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyDisplay {
+    /// `currency:USD`, the symbol of the currency in the locale, such as `$1,234.50`.
+    Symbol,
+    /// `currency_code:USD`, the ISO 4217 code of the currency, such as `USD 1,234.50`.
+    Code,
+}
+
+/// The currencies with a symbol, from CLDR, the other currencies are displayed with their code.
+const CURRENCY_SYMBOLS: &[(&str, &str)] = &[
+    ("AUD", "A$"),
+    ("BRL", "R$"),
+    ("CAD", "CA$"),
+    ("CNY", "CN¥"),
+    ("EUR", "€"),
+    ("GBP", "£"),
+    ("HKD", "HK$"),
+    ("ILS", "₪"),
+    ("INR", "₹"),
+    ("JPY", "¥"),
+    ("KRW", "₩"),
+    ("MXN", "MX$"),
+    ("NZD", "NZ$"),
+    ("PHP", "₱"),
+    ("TWD", "NT$"),
+    ("USD", "$"),
+    ("VND", "₫"),
+];
+
+/// The symbols of the currencies in the locales, such as `¥` of `CNY` in Chinese.
+const LOCALE_CURRENCY_SYMBOLS: &[(&str, &str, &str)] = &[
+    ("en-AU", "AUD", "$"),
+    ("en-AU", "USD", "US$"),
+    ("en-CA", "CAD", "$"),
+    ("en-CA", "USD", "US$"),
+    ("es", "USD", "US$"),
+    ("fr", "USD", "$US"),
+    ("fr-CA", "CAD", "$"),
+    ("fr-CA", "USD", "$\u{A0}US"),
+    ("ja", "CNY", "元"),
+    ("ja", "JPY", "￥"),
+    ("pt", "USD", "US$"),
+    ("ru", "RUB", "₽"),
+    ("zh", "CNY", "¥"),
+    ("zh", "USD", "US$"),
+    ("zh-Hant", "TWD", "$"),
+];
+
+/// The minor unit digits of the currencies, from ISO 4217, the other currencies use 2 digits.
+const CURRENCY_DIGITS: &[(&str, usize)] = &[
+    ("BHD", 3),
+    ("CLP", 0),
+    ("IQD", 3),
+    ("ISK", 0),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("LYD", 3),
+    ("OMR", 3),
+    ("PYG", 0),
+    ("TND", 3),
+    ("UGX", 0),
+    ("VND", 0),
+];
+
+/// Check if the text is an ISO 4217 currency code, such as `USD`.
+pub(crate) fn is_currency_code(code: &str) -> bool {
+    code.len() == 3 && code.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn currency_symbol(locale: &str, code: &str) -> String {
+    let symbol = find_locale(locale, |locale| {
+        LOCALE_CURRENCY_SYMBOLS
+            .iter()
+            .find(|(l, c, _)| *l == locale && *c == code)
+    });
+    if let Some((_, _, symbol)) = symbol {
+        return symbol.to_string();
+    }
+    CURRENCY_SYMBOLS
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, symbol)| symbol.to_string())
+        .unwrap_or_else(|| code.to_string())
+}
+
+/// Format an amount of money in the locale, with the symbol placement and the separators of the locale,
+/// rounded to the minor unit digits of the currency, half to even.
+///
+/// The amount is the decimal text of any number type, such as `1234.5`, so the precision is kept as-is.
+/// The text is returned as-is if it's not a decimal number, or the currency is not an ISO 4217 code.
+///
+/// ```
+/// # use rust_i18n_support::{format_currency, CurrencyDisplay};
+/// assert_eq!(format_currency("en", "1234.5", "USD", CurrencyDisplay::Symbol), "$1,234.50");
+/// assert_eq!(format_currency("de", "1234.5", "EUR", CurrencyDisplay::Symbol), "1.234,50\u{A0}€");
+/// assert_eq!(format_currency("en", "1234.5", "JPY", CurrencyDisplay::Code), "JPY\u{A0}1,234");
+/// ```
+pub fn format_currency(
+    locale: &str,
+    amount: &str,
+    currency: &str,
+    display: CurrencyDisplay,
+) -> String {
+    if !is_currency_code(currency) {
+        return amount.to_string();
+    }
+    let Some((negative, integer, fraction)) = parse_decimal(amount) else {
+        return amount.to_string();
+    };
+
+    let code = currency.to_ascii_uppercase();
+    let digits = CURRENCY_DIGITS
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map_or(2, |(_, digits)| *digits);
+    let (integer, fraction) = round_decimal(integer, fraction, digits);
+    let number = if fraction.is_empty() {
+        integer
+    } else {
+        format!("{}.{}", integer, fraction)
+    };
+    let number = format_number(locale, &number, NumberStyle::Number);
+
+    let symbol = match display {
+        CurrencyDisplay::Symbol => currency_symbol(locale, &code),
+        CurrencyDisplay::Code => code,
+    };
+    // Separate the letters of the symbol from the digits, such as `CHF 12.00` but `$12.00`
+    let pattern = currency_pattern(locale);
+    let pattern = if pattern.contains("¤{}") && symbol.ends_with(|c: char| c.is_alphabetic()) {
+        pattern.replace("¤{}", "¤\u{A0}{}")
+    } else {
+        pattern.to_string()
+    };
+
+    let mut output = String::with_capacity(number.len() + symbol.len() + 4);
+    if negative {
+        output.push_str(minus_sign(locale));
+    }
+    output.push_str(&pattern.replace('¤', &symbol).replace("{}", &number));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_currency() {
+        let format = |locale, amount, currency| {
+            format_currency(locale, amount, currency, CurrencyDisplay::Symbol)
+        };
+        assert_eq!(format("en", "1234.5", "USD"), "$1,234.50");
+        assert_eq!(format("en-US", "-1234.5", "USD"), "-$1,234.50");
+        assert_eq!(format("en", "0.125", "EUR"), "€0.12");
+        assert_eq!(format("en", "1234.5", "CNY"), "CN¥1,234.50");
+        assert_eq!(format("en", "1234.5", "CHF"), "CHF\u{A0}1,234.50");
+        assert_eq!(format("en", "12", "usd"), "$12.00");
+        assert_eq!(format("en-CA", "12", "CAD"), "$12.00");
+        assert_eq!(format("en-IN", "1234567", "INR"), "₹12,34,567.00");
+        assert_eq!(format("de", "1234.5", "EUR"), "1.234,50\u{A0}€");
+        assert_eq!(format("de", "-1234.5", "USD"), "-1.234,50\u{A0}$");
+        assert_eq!(format("de-AT", "1234.5", "EUR"), "€\u{A0}1\u{A0}234,50");
+        assert_eq!(format("de-CH", "1234.5", "CHF"), "CHF\u{A0}1’234.50");
+        assert_eq!(format("fr", "1234.5", "EUR"), "1\u{202F}234,50\u{A0}€");
+        assert_eq!(format("fr", "12", "USD"), "12,00\u{A0}$US");
+        assert_eq!(format("nl", "1234.5", "EUR"), "€\u{A0}1.234,50");
+        assert_eq!(format("pt-BR", "1234.5", "BRL"), "R$\u{A0}1.234,50");
+        assert_eq!(format("pt-PT", "1234.5", "EUR"), "1234,50\u{A0}€");
+        assert_eq!(format("ru", "1234.5", "RUB"), "1\u{A0}234,50\u{A0}₽");
+        assert_eq!(format("zh-CN", "1234.5", "CNY"), "¥1,234.50");
+        assert_eq!(format("ja", "1234.5", "JPY"), "￥1,234");
+        assert_eq!(format("ko", "1234.5", "KRW"), "₩1,234");
+        assert_eq!(format("ar", "1234.5", "USD"), "١٬٢٣٤٫٥٠\u{A0}$");
+        // The minor unit digits
+        assert_eq!(format("en", "1234.5", "JPY"), "¥1,234");
+        assert_eq!(format("en", "1235.5", "JPY"), "¥1,236");
+        assert_eq!(format("en", "1.2345", "KWD"), "KWD\u{A0}1.234");
+        assert_eq!(format("en", "9.995", "USD"), "$10.00");
+        // Not a number or a currency code
+        assert_eq!(format("en", "NaN", "USD"), "NaN");
+        assert_eq!(format("en", "12", "US$"), "12");
+        assert_eq!(format("en", "12", "DOLLAR"), "12");
+    }
+
+    #[test]
+    fn test_format_currency_code() {
+        let format = |locale, amount, currency| {
+            format_currency(locale, amount, currency, CurrencyDisplay::Code)
+        };
+        assert_eq!(format("en", "1234.5", "USD"), "USD\u{A0}1,234.50");
+        assert_eq!(format("en", "-1234.5", "EUR"), "-EUR\u{A0}1,234.50");
+        assert_eq!(format("de", "1234.5", "USD"), "1.234,50\u{A0}USD");
+        assert_eq!(format("zh", "1234.5", "CNY"), "CNY\u{A0}1,234.50");
+    }
+}
@@ -0,0 +1,1137 @@
+use crate::locale::locale_entry;
+
+/// The style of a locale-aware date or time, selected by `%{name:date_short}` in a translation
+/// or `name = value : {date_short}` in `t!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeStyle {
+    /// `date_short`, such as `3/5/24`.
+    DateShort,
+    /// `date_medium`, such as `Mar 5, 2024`.
+    DateMedium,
+    /// `date_long`, such as `March 5, 2024`.
+    DateLong,
+    /// `date_full`, such as `Tuesday, March 5, 2024`.
+    DateFull,
+    /// `time_short`, such as `2:30 PM`.
+    TimeShort,
+    /// `time_medium`, such as `2:30:05 PM`.
+    TimeMedium,
+    /// `datetime_short`, such as `3/5/24, 2:30 PM`.
+    DateTimeShort,
+    /// `datetime_medium`, such as `Mar 5, 2024, 2:30:05 PM`.
+    DateTimeMedium,
+    /// `datetime_long`, such as `March 5, 2024 at 2:30:05 PM`.
+    DateTimeLong,
+    /// `datetime_full`, such as `Tuesday, March 5, 2024 at 2:30:05 PM`.
+    DateTimeFull,
+}
+
+impl DateTimeStyle {
+    /// Parse the style name, returns `None` if it's not a date or time style.
+    pub fn parse(style: &str) -> Option<Self> {
+        Some(match style {
+            "date_short" => Self::DateShort,
+            "date_medium" => Self::DateMedium,
+            "date_long" => Self::DateLong,
+            "date_full" => Self::DateFull,
+            "time_short" => Self::TimeShort,
+            "time_medium" => Self::TimeMedium,
+            "datetime_short" => Self::DateTimeShort,
+            "datetime_medium" => Self::DateTimeMedium,
+            "datetime_long" => Self::DateTimeLong,
+            "datetime_full" => Self::DateTimeFull,
+            _ => return None,
+        })
+    }
+}
+
+/// The CLDR names and patterns to format the dates and times in a locale.
+struct DateTimeSymbols {
+    months: [&'static str; 12],
+    months_abbr: [&'static str; 12],
+    /// The weekdays from Sunday.
+    weekdays: [&'static str; 7],
+    weekdays_abbr: [&'static str; 7],
+    am_pm: [&'static str; 2],
+    /// The localized GMT format, such as `GMT` in `GMT+8`.
+    gmt: &'static str,
+    /// The date patterns of short, medium, long and full.
+    date: [&'static str; 4],
+    /// The time patterns of short, medium and long, the long pattern has the `z` zone.
+    time: [&'static str; 3],
+    /// The patterns to join the date (`{1}`) and the time (`{0}`), of short, medium, long and full.
+    datetime: [&'static str; 4],
+}
+
+const EN: DateTimeSymbols = DateTimeSymbols {
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    months_abbr: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    weekdays: [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ],
+    weekdays_abbr: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+    am_pm: ["AM", "PM"],
+    gmt: "GMT",
+    date: ["M/d/yy", "MMM d, y", "MMMM d, y", "EEEE, MMMM d, y"],
+    time: ["h:mm\u{202F}a", "h:mm:ss\u{202F}a", "h:mm:ss\u{202F}a z"],
+    datetime: ["{1}, {0}", "{1}, {0}", "{1} 'at' {0}", "{1} 'at' {0}"],
+};
+
+const NUMERIC_MONTHS: [&str; 12] = [
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12",
+];
+
+/// The date and time symbols of the locales, from CLDR, the other locales use [`EN`].
+const LOCALE_SYMBOLS: &[(&str, DateTimeSymbols)] = &[
+    (
+        "de",
+        DateTimeSymbols {
+            months: [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            months_abbr: [
+                "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+                "Nov.", "Dez.",
+            ],
+            weekdays: [
+                "Sonntag",
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+            ],
+            weekdays_abbr: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+            am_pm: ["AM", "PM"],
+            gmt: "GMT",
+            date: ["dd.MM.yy", "dd.MM.y", "d. MMMM y", "EEEE, d. MMMM y"],
+            time: ["HH:mm", "HH:mm:ss", "HH:mm:ss z"],
+            datetime: ["{1}, {0}", "{1}, {0}", "{1} 'um' {0}", "{1} 'um' {0}"],
+        },
+    ),
+    (
+        "en-GB",
+        DateTimeSymbols {
+            am_pm: ["am", "pm"],
+            date: ["dd/MM/y", "d MMM y", "d MMMM y", "EEEE d MMMM y"],
+            time: ["HH:mm", "HH:mm:ss", "HH:mm:ss z"],
+            ..EN
+        },
+    ),
+    (
+        "es",
+        DateTimeSymbols {
+            months: [
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+            months_abbr: [
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ],
+            weekdays: [
+                "domingo",
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+            ],
+            weekdays_abbr: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+            am_pm: ["a.\u{A0}m.", "p.\u{A0}m."],
+            gmt: "GMT",
+            date: [
+                "d/M/yy",
+                "d MMM y",
+                "d 'de' MMMM 'de' y",
+                "EEEE, d 'de' MMMM 'de' y",
+            ],
+            time: ["H:mm", "H:mm:ss", "H:mm:ss z"],
+            datetime: ["{1}, {0}", "{1}, {0}", "{1}, {0}", "{1}, {0}"],
+        },
+    ),
+    (
+        "fr",
+        DateTimeSymbols {
+            months: [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            months_abbr: [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+            weekdays: [
+                "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+            ],
+            weekdays_abbr: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+            am_pm: ["AM", "PM"],
+            gmt: "UTC",
+            date: ["dd/MM/y", "d MMM y", "d MMMM y", "EEEE d MMMM y"],
+            time: ["HH:mm", "HH:mm:ss", "HH:mm:ss z"],
+            datetime: ["{1} {0}", "{1}, {0}", "{1} 'à' {0}", "{1} 'à' {0}"],
+        },
+    ),
+    (
+        "it",
+        DateTimeSymbols {
+            months: [
+                "gennaio",
+                "febbraio",
+                "marzo",
+                "aprile",
+                "maggio",
+                "giugno",
+                "luglio",
+                "agosto",
+                "settembre",
+                "ottobre",
+                "novembre",
+                "dicembre",
+            ],
+            months_abbr: [
+                "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+            ],
+            weekdays: [
+                "domenica",
+                "lunedì",
+                "martedì",
+                "mercoledì",
+                "giovedì",
+                "venerdì",
+                "sabato",
+            ],
+            weekdays_abbr: ["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+            am_pm: ["AM", "PM"],
+            gmt: "GMT",
+            date: ["dd/MM/yy", "d MMM y", "d MMMM y", "EEEE d MMMM y"],
+            time: ["HH:mm", "HH:mm:ss", "HH:mm:ss z"],
+            datetime: [
+                "{1}, {0}",
+                "{1}, {0}",
+                "{1} 'alle ore' {0}",
+                "{1} 'alle ore' {0}",
+            ],
+        },
+    ),
+    (
+        "ja",
+        DateTimeSymbols {
+            months: [
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ],
+            months_abbr: [
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ],
+            weekdays: [
+                "日曜日",
+                "月曜日",
+                "火曜日",
+                "水曜日",
+                "木曜日",
+                "金曜日",
+                "土曜日",
+            ],
+            weekdays_abbr: ["日", "月", "火", "水", "木", "金", "土"],
+            am_pm: ["午前", "午後"],
+            gmt: "GMT",
+            date: ["y/MM/dd", "y/MM/dd", "y年M月d日", "y年M月d日EEEE"],
+            time: ["H:mm", "H:mm:ss", "H:mm:ss z"],
+            datetime: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+        },
+    ),
+    (
+        "ko",
+        DateTimeSymbols {
+            months: [
+                "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월",
+                "12월",
+            ],
+            months_abbr: [
+                "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월",
+                "12월",
+            ],
+            weekdays: [
+                "일요일",
+                "월요일",
+                "화요일",
+                "수요일",
+                "목요일",
+                "금요일",
+                "토요일",
+            ],
+            weekdays_abbr: ["일", "월", "화", "수", "목", "금", "토"],
+            am_pm: ["오전", "오후"],
+            gmt: "GMT",
+            date: ["yy. M. d.", "y. M. d.", "y년 M월 d일", "y년 M월 d일 EEEE"],
+            time: ["a h:mm", "a h:mm:ss", "a h시 m분 s초 z"],
+            datetime: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+        },
+    ),
+    (
+        "pt",
+        DateTimeSymbols {
+            months: [
+                "janeiro",
+                "fevereiro",
+                "março",
+                "abril",
+                "maio",
+                "junho",
+                "julho",
+                "agosto",
+                "setembro",
+                "outubro",
+                "novembro",
+                "dezembro",
+            ],
+            months_abbr: [
+                "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.",
+                "nov.", "dez.",
+            ],
+            weekdays: [
+                "domingo",
+                "segunda-feira",
+                "terça-feira",
+                "quarta-feira",
+                "quinta-feira",
+                "sexta-feira",
+                "sábado",
+            ],
+            weekdays_abbr: ["dom.", "seg.", "ter.", "qua.", "qui.", "sex.", "sáb."],
+            am_pm: ["AM", "PM"],
+            gmt: "GMT",
+            date: [
+                "dd/MM/y",
+                "d 'de' MMM 'de' y",
+                "d 'de' MMMM 'de' y",
+                "EEEE, d 'de' MMMM 'de' y",
+            ],
+            time: ["HH:mm", "HH:mm:ss", "HH:mm:ss z"],
+            datetime: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+        },
+    ),
+    (
+        "ru",
+        DateTimeSymbols {
+            months: [
+                "января",
+                "февраля",
+                "марта",
+                "апреля",
+                "мая",
+                "июня",
+                "июля",
+                "августа",
+                "сентября",
+                "октября",
+                "ноября",
+                "декабря",
+            ],
+            months_abbr: [
+                "янв.",
+                "февр.",
+                "мар.",
+                "апр.",
+                "мая",
+                "июн.",
+                "июл.",
+                "авг.",
+                "сент.",
+                "окт.",
+                "нояб.",
+                "дек.",
+            ],
+            weekdays: [
+                "воскресенье",
+                "понедельник",
+                "вторник",
+                "среда",
+                "четверг",
+                "пятница",
+                "суббота",
+            ],
+            weekdays_abbr: ["вс", "пн", "вт", "ср", "чт", "пт", "сб"],
+            am_pm: ["AM", "PM"],
+            gmt: "GMT",
+            date: [
+                "dd.MM.y",
+                "d MMM y 'г'.",
+                "d MMMM y 'г'.",
+                "EEEE, d MMMM y 'г'.",
+            ],
+            time: ["HH:mm", "HH:mm:ss", "HH:mm:ss z"],
+            datetime: ["{1}, {0}", "{1}, {0}", "{1}, {0}", "{1}, {0}"],
+        },
+    ),
+    (
+        "zh",
+        DateTimeSymbols {
+            months: [
+                "一月",
+                "二月",
+                "三月",
+                "四月",
+                "五月",
+                "六月",
+                "七月",
+                "八月",
+                "九月",
+                "十月",
+                "十一月",
+                "十二月",
+            ],
+            months_abbr: NUMERIC_MONTHS,
+            weekdays: [
+                "星期日",
+                "星期一",
+                "星期二",
+                "星期三",
+                "星期四",
+                "星期五",
+                "星期六",
+            ],
+            weekdays_abbr: ["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+            am_pm: ["上午", "下午"],
+            gmt: "GMT",
+            date: ["y/M/d", "y年M月d日", "y年M月d日", "y年M月d日EEEE"],
+            time: ["HH:mm", "HH:mm:ss", "z HH:mm:ss"],
+            datetime: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+        },
+    ),
+];
+
+/// Find the symbols of the locale, or of its language, such as `de-AT` -> `de`.
+fn datetime_symbols(locale: &str) -> &'static DateTimeSymbols {
+    locale_entry(LOCALE_SYMBOLS, locale).unwrap_or(&EN)
+}
+
+/// A date, a time or a date time to format with the date and time styles, such as `name = value : {date_short}` in `t!`.
+///
+/// It's implemented for the ISO 8601 texts, and for the dates and times of [`chrono`](https://docs.rs/chrono)
+/// and [`time`](https://docs.rs/time) with the `chrono` and `time` features, so the other values fail to compile
+/// with a date or time style. Implement it for the other date time types with their ISO 8601 text.
+pub trait DateTimeValue {
+    /// The ISO 8601 text of the value, such as `2024-03-05`, `14:30:05` or `2024-03-05T14:30:05+08:00`.
+    fn to_iso8601(&self) -> String;
+}
+
+impl DateTimeValue for str {
+    fn to_iso8601(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl DateTimeValue for String {
+    fn to_iso8601(&self) -> String {
+        self.clone()
+    }
+}
+
+impl<T: DateTimeValue + ?Sized> DateTimeValue for &T {
+    fn to_iso8601(&self) -> String {
+        (**self).to_iso8601()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl DateTimeValue for chrono::NaiveDate {
+    fn to_iso8601(&self) -> String {
+        use chrono::Datelike;
+        iso_date(self.year(), self.month(), self.day())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl DateTimeValue for chrono::NaiveTime {
+    fn to_iso8601(&self) -> String {
+        use chrono::Timelike;
+        iso_time(self.hour(), self.minute(), self.second())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl DateTimeValue for chrono::NaiveDateTime {
+    fn to_iso8601(&self) -> String {
+        format!("{}T{}", self.date().to_iso8601(), self.time().to_iso8601())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> DateTimeValue for chrono::DateTime<Tz> {
+    fn to_iso8601(&self) -> String {
+        use chrono::Offset;
+        let offset = self.offset().fix().local_minus_utc();
+        format!("{}{}", self.naive_local().to_iso8601(), iso_offset(offset))
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateTimeValue for time::Date {
+    fn to_iso8601(&self) -> String {
+        iso_date(
+            self.year(),
+            u8::from(self.month()).into(),
+            self.day().into(),
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateTimeValue for time::Time {
+    fn to_iso8601(&self) -> String {
+        iso_time(
+            self.hour().into(),
+            self.minute().into(),
+            self.second().into(),
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateTimeValue for time::PrimitiveDateTime {
+    fn to_iso8601(&self) -> String {
+        format!("{}T{}", self.date().to_iso8601(), self.time().to_iso8601())
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateTimeValue for time::OffsetDateTime {
+    fn to_iso8601(&self) -> String {
+        format!(
+            "{}T{}{}",
+            self.date().to_iso8601(),
+            self.time().to_iso8601(),
+            iso_offset(self.offset().whole_seconds())
+        )
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn iso_date(year: i32, month: u32, day: u32) -> String {
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn iso_time(hour: u32, minute: u32, second: u32) -> String {
+    format!("{:02}:{:02}:{:02}", hour, minute, second)
+}
+
+/// The offset in seconds, such as `+08:00` or `-03:30`, with the seconds if any.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn iso_offset(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    let (hours, minutes, seconds) = (offset / 3600, offset / 60 % 60, offset % 60);
+    if seconds == 0 {
+        format!("{}{:02}:{:02}", sign, hours, minutes)
+    } else {
+        format!("{}{:02}:{:02}:{:02}", sign, hours, minutes, seconds)
+    }
+}
+
+/// The date and the time fields of a value, and the offset in seconds if it's known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DateTime {
+    date: Option<(i32, u32, u32)>,
+    time: Option<(u32, u32, u32)>,
+    offset: Option<i32>,
+}
+
+/// Format a date, a time or a date time in the locale, with the CLDR names and patterns of the locale.
+///
+/// The value is a [`DateTimeValue`], such as the dates and times of `chrono` and `time` with their features,
+/// or the ISO 8601 text of any date time type, such as their `Display` output:
+///
+/// - A date, such as `2024-03-05`.
+/// - A time, such as `14:30:05`, `14:30:05.123` or `9:05:00.0`.
+/// - A date time, such as `2024-03-05 14:30:05`, `2024-03-05T14:30:05Z` or `2024-03-05 14:30:05 +08:00`.
+///
+/// The time is formatted in its offset, which is shown as `GMT+8` in the `datetime_long` and `datetime_full` styles.
+/// The time zone names other than `UTC` and `GMT`, such as `CET`, are ignored.
+///
+/// The text is returned as-is if it's not a valid date time, or the style needs the missing date or time.
+///
+/// The CLDR data covers `en`, `en-GB`, `de`, `es`, `fr`, `it`, `ja`, `ko`, `pt`, `ru` and `zh`,
+/// the other locales are formatted in English.
+///
+/// ```
+/// # use rust_i18n_support::{format_datetime, DateTimeStyle};
+/// assert_eq!(format_datetime("en", "2024-03-05", DateTimeStyle::DateLong), "March 5, 2024");
+/// assert_eq!(format_datetime("de", "2024-03-05", DateTimeStyle::DateLong), "5. März 2024");
+/// assert_eq!(format_datetime("de", "2024-03-05 14:30:05", DateTimeStyle::TimeShort), "14:30");
+/// assert_eq!(
+///     format_datetime("de", "2024-03-05T14:30:05+08:00", DateTimeStyle::DateTimeLong),
+///     "5. März 2024 um 14:30:05 GMT+8"
+/// );
+/// ```
+pub fn format_datetime<V: DateTimeValue + ?Sized>(
+    locale: &str,
+    value: &V,
+    style: DateTimeStyle,
+) -> String {
+    let value = value.to_iso8601();
+    let Some(datetime) = parse_datetime(&value) else {
+        return value;
+    };
+
+    let symbols = datetime_symbols(locale);
+    // The long time pattern shows the offset, so it's only used if the offset is known
+    let long_time = if datetime.offset.is_some() { 2 } else { 1 };
+    let (date, time, datetime_index) = match style {
+        DateTimeStyle::DateShort => (Some(0), None, 0),
+        DateTimeStyle::DateMedium => (Some(1), None, 0),
+        DateTimeStyle::DateLong => (Some(2), None, 0),
+        DateTimeStyle::DateFull => (Some(3), None, 0),
+        DateTimeStyle::TimeShort => (None, Some(0), 0),
+        DateTimeStyle::TimeMedium => (None, Some(1), 0),
+        DateTimeStyle::DateTimeShort => (Some(0), Some(0), 0),
+        DateTimeStyle::DateTimeMedium => (Some(1), Some(1), 1),
+        DateTimeStyle::DateTimeLong => (Some(2), Some(long_time), 2),
+        DateTimeStyle::DateTimeFull => (Some(3), Some(long_time), 3),
+    };
+    if date.is_some() && datetime.date.is_none() || time.is_some() && datetime.time.is_none() {
+        return value;
+    }
+
+    let date = date.map(|i| format_pattern(symbols.date[i], symbols, &datetime));
+    let time = time.map(|i| format_pattern(symbols.time[i], symbols, &datetime));
+    match (date, time) {
+        (Some(date), Some(time)) => {
+            // The `{0}` and `{1}` are replaced after the quoted literals are resolved
+            let glue = format_pattern(symbols.datetime[datetime_index], symbols, &datetime);
+            glue.replace("{1}", &date).replace("{0}", &time)
+        }
+        (Some(date), None) => date,
+        (None, Some(time)) => time,
+        (None, None) => value,
+    }
+}
+
+/// Format the fields of a CLDR date time pattern, such as `EEEE, MMMM d, y`, and the `'...'` quoted literals.
+fn format_pattern(pattern: &str, symbols: &DateTimeSymbols, datetime: &DateTime) -> String {
+    let (year, month, day) = datetime.date.unwrap_or((1970, 1, 1));
+    let (hour, minute, second) = datetime.time.unwrap_or_default();
+
+    let mut output = String::with_capacity(pattern.len() * 2);
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            if chars.peek() == Some(&'\'') {
+                chars.next();
+                output.push('\'');
+                continue;
+            }
+            for c in chars.by_ref() {
+                if c == '\'' {
+                    break;
+                }
+                output.push(c);
+            }
+            continue;
+        }
+        if !c.is_ascii_alphabetic() {
+            output.push(c);
+            continue;
+        }
+
+        let mut count = 1;
+        while chars.peek() == Some(&c) {
+            chars.next();
+            count += 1;
+        }
+        let padded = |value: u32| {
+            if count >= 2 {
+                format!("{:02}", value)
+            } else {
+                value.to_string()
+            }
+        };
+        match c {
+            'y' if count == 2 => output.push_str(&format!("{:02}", year.rem_euclid(100))),
+            'y' => output.push_str(&format!("{:0width$}", year, width = count)),
+            'M' => match count {
+                1 | 2 => output.push_str(&padded(month)),
+                3 => output.push_str(symbols.months_abbr[month as usize - 1]),
+                _ => output.push_str(symbols.months[month as usize - 1]),
+            },
+            'd' => output.push_str(&padded(day)),
+            'E' => {
+                let weekday = weekday(year, month, day);
+                match count {
+                    1..=3 => output.push_str(symbols.weekdays_abbr[weekday]),
+                    _ => output.push_str(symbols.weekdays[weekday]),
+                }
+            }
+            'H' => output.push_str(&padded(hour)),
+            'h' => output.push_str(&padded(if hour % 12 == 0 { 12 } else { hour % 12 })),
+            'm' => output.push_str(&padded(minute)),
+            's' => output.push_str(&padded(second)),
+            'a' => output.push_str(symbols.am_pm[usize::from(hour >= 12)]),
+            'z' => {
+                if let Some(offset) = datetime.offset {
+                    output.push_str(&gmt_offset(symbols.gmt, offset));
+                }
+            }
+            _ => (0..count).for_each(|_| output.push(c)),
+        }
+    }
+    output
+}
+
+/// The localized GMT format of the offset, such as `GMT+8`, `GMT-3:30` or `GMT` for UTC.
+fn gmt_offset(gmt: &str, offset: i32) -> String {
+    if offset == 0 {
+        return gmt.to_string();
+    }
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    let (hours, minutes, seconds) = (offset / 3600, offset / 60 % 60, offset % 60);
+    match (minutes, seconds) {
+        (0, 0) => format!("{}{}{}", gmt, sign, hours),
+        (_, 0) => format!("{}{}{}:{:02}", gmt, sign, hours, minutes),
+        _ => format!("{}{}{}:{:02}:{:02}", gmt, sign, hours, minutes, seconds),
+    }
+}
+
+/// The weekday of the date, `0` for Sunday.
+fn weekday(year: i32, month: u32, day: u32) -> usize {
+    // Sakamoto's method
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if month < 3 { year - 1 } else { year };
+    let weekday = year + year.div_euclid(4) - year.div_euclid(100)
+        + year.div_euclid(400)
+        + OFFSETS[month as usize - 1]
+        + day as i32;
+    weekday.rem_euclid(7) as usize
+}
+
+/// Parse a date, a time or a date time in ISO 8601.
+fn parse_datetime(value: &str) -> Option<DateTime> {
+    let value = value.trim();
+    if let Some(time) = parse_time(value) {
+        return Some(DateTime {
+            date: None,
+            time: Some(time),
+            offset: None,
+        });
+    }
+
+    let (date, rest) = match value.find([' ', 'T']) {
+        Some(pos) => (&value[..pos], Some(&value[pos + 1..])),
+        None => (value, None),
+    };
+    let date = parse_date(date)?;
+    let (time, offset) = match rest {
+        Some(rest) => {
+            // The offset or the time zone follows the time, such as `Z`, `+08:00` or ` UTC`
+            let end = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == ':' || c == '.'))
+                .unwrap_or(rest.len());
+            (Some(parse_time(&rest[..end])?), parse_offset(&rest[end..])?)
+        }
+        None => (None, None),
+    };
+    Some(DateTime {
+        date: Some(date),
+        time,
+        offset,
+    })
+}
+
+/// Parse the offset after a time in seconds, such as `Z`, `UTC`, `+08:00`, `+0800` or `+08:00:00`,
+/// returns `Some(None)` if there's no offset, or it's a time zone name with an unknown offset, such as `CET`.
+fn parse_offset(value: &str) -> Option<Option<i32>> {
+    let value = value.trim_start();
+    let (sign, rest) = match value {
+        "" => return Some(None),
+        "Z" | "UTC" | "GMT" => return Some(Some(0)),
+        _ if value.bytes().all(|b| b.is_ascii_alphabetic()) => return Some(None),
+        _ => match value.strip_prefix('+') {
+            Some(rest) => (1, rest),
+            None => (-1, value.strip_prefix('-')?),
+        },
+    };
+
+    let mut parts = rest.split(':');
+    let hours = parts.next()?;
+    let (hours, minutes) = match (hours.len(), hours.get(2..)) {
+        // The basic format, such as `+0800`
+        (4, Some(minutes)) => (parse_digits(&hours[..2], 2)?, parse_digits(minutes, 2)?),
+        _ => (
+            parse_digits(hours, 2)?,
+            parts
+                .next()
+                .map_or(Some(0), |minutes| parse_digits(minutes, 2))?,
+        ),
+    };
+    let seconds = parts
+        .next()
+        .map_or(Some(0), |seconds| parse_digits(seconds, 2))?;
+    if parts.next().is_some() || hours > 23 || minutes > 59 || seconds > 59 {
+        return None;
+    }
+    Some(Some(sign * (hours * 3600 + minutes * 60 + seconds) as i32))
+}
+
+fn parse_date(value: &str) -> Option<(i32, u32, u32)> {
+    let (year, rest) = match value.strip_prefix('-') {
+        Some(rest) => {
+            let (year, rest) = rest.split_once('-')?;
+            (format!("-{}", year), rest)
+        }
+        None => {
+            let (year, rest) = value.split_once('-')?;
+            (year.to_string(), rest)
+        }
+    };
+    let (month, day) = rest.split_once('-')?;
+    let year = year.parse().ok()?;
+    let month = parse_digits(month, 2)?;
+    let day = parse_digits(day, 2)?;
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn parse_time(value: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = value.split(':');
+    // The hour of the `time` crate is not padded, such as `9:05:00.0`
+    let hour = parts.next()?;
+    let hour = parse_digits(hour, 1).or_else(|| parse_digits(hour, 2))?;
+    let minute = parse_digits(parts.next()?, 2)?;
+    let second = match parts.next() {
+        Some(second) => {
+            let (second, fraction) = second.split_once('.').unwrap_or((second, "0"));
+            if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            parse_digits(second, 2)?
+        }
+        None => 0,
+    };
+    if parts.next().is_some() || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    Some((hour, minute, second))
+}
+
+fn parse_digits(value: &str, len: usize) -> Option<u32> {
+    if value.len() != len || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_datetime() {
+        let datetime = |date, time| DateTime {
+            date,
+            time,
+            offset: None,
+        };
+        assert_eq!(
+            parse_datetime("2024-03-05"),
+            Some(datetime(Some((2024, 3, 5)), None))
+        );
+        assert_eq!(
+            parse_datetime("14:30:05.123"),
+            Some(datetime(None, Some((14, 30, 5))))
+        );
+        assert_eq!(
+            parse_datetime("14:30"),
+            Some(datetime(None, Some((14, 30, 0))))
+        );
+        let fields = datetime(Some((2024, 3, 5)), Some((14, 30, 5)));
+        let expected = Some(fields);
+        let with_offset = |offset| {
+            Some(DateTime {
+                offset: Some(offset),
+                ..fields
+            })
+        };
+        // chrono
+        assert_eq!(parse_datetime("2024-03-05 14:30:05"), expected);
+        assert_eq!(parse_datetime("2024-03-05 14:30:05 UTC"), with_offset(0));
+        assert_eq!(
+            parse_datetime("2024-03-05 14:30:05.250 +08:00"),
+            with_offset(8 * 3600)
+        );
+        // time
+        assert_eq!(parse_datetime("2024-03-05 14:30:05.0"), expected);
+        assert_eq!(
+            parse_datetime("2024-03-05 14:30:05.0 +00:00:00"),
+            with_offset(0)
+        );
+        assert_eq!(
+            parse_datetime("2024-03-05 9:05:00.0 -05:30:15"),
+            Some(DateTime {
+                offset: Some(-(5 * 3600 + 30 * 60 + 15)),
+                ..datetime(Some((2024, 3, 5)), Some((9, 5, 0)))
+            })
+        );
+        assert_eq!(
+            parse_datetime("9:05:00.0"),
+            Some(datetime(None, Some((9, 5, 0))))
+        );
+        // RFC 3339
+        assert_eq!(parse_datetime("2024-03-05T14:30:05Z"), with_offset(0));
+        assert_eq!(
+            parse_datetime("2024-03-05T14:30:05-05:00"),
+            with_offset(-5 * 3600)
+        );
+        assert_eq!(
+            parse_datetime("2024-03-05T14:30:05+0530"),
+            with_offset(5 * 3600 + 30 * 60)
+        );
+        // The offsets of the time zone names are unknown
+        assert_eq!(parse_datetime("2024-03-05 14:30:05 CET"), expected);
+
+        assert_eq!(parse_datetime(""), None);
+        assert_eq!(parse_datetime("hello"), None);
+        assert_eq!(parse_datetime("2024-13-05"), None);
+        assert_eq!(parse_datetime("2024-02-31"), None);
+        assert_eq!(parse_datetime("2023-02-29"), None);
+        assert_eq!(parse_datetime("2024-04-31"), None);
+        assert_eq!(parse_datetime("2024-03-05 14:30:05 +25:00"), None);
+        assert_eq!(parse_datetime("2024-03-05 14:30:05 +08:00 x"), None);
+        assert_eq!(parse_datetime("2024-03-05 14:30:05 +8"), None);
+        assert_eq!(parse_datetime("2024-3-5"), None);
+        assert_eq!(parse_datetime("25:00"), None);
+        assert_eq!(parse_datetime("123:00"), None);
+        assert_eq!(parse_datetime(":05"), None);
+        assert_eq!(parse_datetime("1234"), None);
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2023, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2024, 4), 30);
+        assert_eq!(days_in_month(2024, 12), 31);
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(weekday(2024, 3, 5), 2);
+        assert_eq!(weekday(2000, 1, 1), 6);
+        assert_eq!(weekday(1970, 1, 1), 4);
+        assert_eq!(weekday(2024, 2, 29), 4);
+    }
+
+    #[test]
+    fn test_format_datetime() {
+        let value = "2024-03-05 14:30:05";
+        let format = |locale, style| format_datetime(locale, value, style);
+        use DateTimeStyle::*;
+
+        assert_eq!(format("en", DateShort), "3/5/24");
+        assert_eq!(format("en", DateMedium), "Mar 5, 2024");
+        assert_eq!(format("en", DateLong), "March 5, 2024");
+        assert_eq!(format("en", DateFull), "Tuesday, March 5, 2024");
+        assert_eq!(format("en", TimeShort), "2:30\u{202F}PM");
+        assert_eq!(format("en", TimeMedium), "2:30:05\u{202F}PM");
+        assert_eq!(format("en", DateTimeShort), "3/5/24, 2:30\u{202F}PM");
+        assert_eq!(
+            format("en-US", DateTimeLong),
+            "March 5, 2024 at 2:30:05\u{202F}PM"
+        );
+        assert_eq!(format("en-GB", DateShort), "05/03/2024");
+        assert_eq!(format("en-GB", DateFull), "Tuesday 5 March 2024");
+        assert_eq!(format("en-GB", TimeShort), "14:30");
+
+        assert_eq!(format("de", DateShort), "05.03.24");
+        assert_eq!(format("de", DateMedium), "05.03.2024");
+        assert_eq!(format("de-AT", DateFull), "Dienstag, 5. März 2024");
+        assert_eq!(format("de", DateTimeLong), "5. März 2024 um 14:30:05");
+        assert_eq!(format("fr", DateFull), "mardi 5 mars 2024");
+        assert_eq!(format("fr", DateTimeLong), "5 mars 2024 à 14:30:05");
+        assert_eq!(format("es", DateLong), "5 de marzo de 2024");
+        assert_eq!(format("es", TimeShort), "14:30");
+        assert_eq!(format("it", DateMedium), "5 mar 2024");
+        assert_eq!(format("pt-BR", DateLong), "5 de março de 2024");
+        assert_eq!(format("ru", DateLong), "5 марта 2024 г.");
+        assert_eq!(format("ja", DateFull), "2024年3月5日火曜日");
+        assert_eq!(format("zh-CN", DateLong), "2024年3月5日");
+        assert_eq!(format("zh", DateTimeShort), "2024/3/5 14:30");
+        assert_eq!(format("ko", DateLong), "2024년 3월 5일");
+        assert_eq!(format("ko", TimeShort), "오후 2:30");
+
+        // The hour of the `time` crate is not padded
+        let morning = "2024-03-05 9:05:00.0 +00:00:00";
+        assert_eq!(format_datetime("en", morning, TimeShort), "9:05\u{202F}AM");
+        assert_eq!(format_datetime("de", morning, TimeShort), "09:05");
+        // Midnight and noon in the 12-hour clock
+        assert_eq!(format_datetime("en", "00:05", TimeShort), "12:05\u{202F}AM");
+        assert_eq!(format_datetime("en", "12:05", TimeShort), "12:05\u{202F}PM");
+        // The offset is shown in the long and full styles
+        let value = "2024-03-05T14:30:05+08:00";
+        assert_eq!(
+            format_datetime("en", value, DateTimeLong),
+            "March 5, 2024 at 2:30:05\u{202F}PM GMT+8"
+        );
+        assert_eq!(
+            format_datetime("fr", value, DateTimeFull),
+            "mardi 5 mars 2024 à 14:30:05 UTC+8"
+        );
+        assert_eq!(
+            format_datetime("zh", "2024-03-05T14:30:05-03:30", DateTimeLong),
+            "2024年3月5日 GMT-3:30 14:30:05"
+        );
+        assert_eq!(
+            format_datetime("de", "2024-03-05T14:30:05Z", DateTimeLong),
+            "5. März 2024 um 14:30:05 GMT"
+        );
+        assert_eq!(
+            format_datetime("de", value, DateTimeShort),
+            "05.03.24, 14:30"
+        );
+        // The missing date or time
+        assert_eq!(format_datetime("en", "14:30", DateShort), "14:30");
+        assert_eq!(format_datetime("en", "2024-03-05", TimeShort), "2024-03-05");
+        assert_eq!(format_datetime("en", "tomorrow", DateShort), "tomorrow");
+        assert_eq!(format_datetime("en", "2024-02-30", DateShort), "2024-02-30");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_format_chrono() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        let datetime = date.and_hms_opt(9, 5, 0).unwrap();
+        assert_eq!(date.to_iso8601(), "2024-03-05");
+        assert_eq!(datetime.time().to_iso8601(), "09:05:00");
+        assert_eq!(datetime.to_iso8601(), "2024-03-05T09:05:00");
+        assert_eq!(
+            format_datetime("en", &date, DateTimeStyle::DateLong),
+            "March 5, 2024"
+        );
+        assert_eq!(
+            format_datetime("de", &datetime.time(), DateTimeStyle::TimeShort),
+            "09:05"
+        );
+
+        let offset = FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap();
+        let local = offset.from_local_datetime(&datetime).unwrap();
+        assert_eq!(local.to_iso8601(), "2024-03-05T09:05:00-03:30");
+        assert_eq!(
+            format_datetime("en", &local, DateTimeStyle::DateTimeLong),
+            "March 5, 2024 at 9:05:00\u{202F}AM GMT-3:30"
+        );
+        let utc = Utc.from_utc_datetime(&datetime);
+        assert_eq!(utc.to_iso8601(), "2024-03-05T09:05:00+00:00");
+        assert_eq!(
+            format_datetime("en-GB", &utc, DateTimeStyle::DateTimeFull),
+            "Tuesday 5 March 2024 at 09:05:00 GMT"
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_format_time() {
+        use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+        let date = Date::from_calendar_date(2024, Month::March, 5).unwrap();
+        let datetime = PrimitiveDateTime::new(date, Time::from_hms(9, 5, 0).unwrap());
+        assert_eq!(date.to_iso8601(), "2024-03-05");
+        assert_eq!(datetime.time().to_iso8601(), "09:05:00");
+        assert_eq!(datetime.to_iso8601(), "2024-03-05T09:05:00");
+        assert_eq!(
+            format_datetime("ja", &date, DateTimeStyle::DateFull),
+            "2024年3月5日火曜日"
+        );
+
+        let offset = UtcOffset::from_hms(5, 30, 0).unwrap();
+        let local = datetime.assume_offset(offset);
+        assert_eq!(local.to_iso8601(), "2024-03-05T09:05:00+05:30");
+        assert_eq!(
+            format_datetime("ko", &local, DateTimeStyle::DateTimeLong),
+            "2024년 3월 5일 오전 9시 5분 0초 GMT+5:30"
+        );
+    }
+
+    #[test]
+    fn test_datetime_style() {
+        assert_eq!(
+            DateTimeStyle::parse("date_short"),
+            Some(DateTimeStyle::DateShort)
+        );
+        assert_eq!(
+            DateTimeStyle::parse("datetime_long"),
+            Some(DateTimeStyle::DateTimeLong)
+        );
+        assert_eq!(DateTimeStyle::parse("number"), None);
+    }
+}
@@ -1,21 +1,61 @@
 use normpath::PathExt;
 use std::fs::File;
 use std::io::prelude::*;
-use std::{collections::HashMap, path::Path};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
 mod atomic_str;
 mod backend;
 mod config;
 mod cow_str;
+mod currency;
+#[cfg(feature = "datetime")]
+mod datetime;
+mod link;
+mod list;
+mod locale;
+mod message_format;
 mod minify_key;
+mod negotiate;
+mod number;
+mod placeholder;
+mod plural;
+mod pseudo;
+mod relative;
+mod style;
+mod unit;
 pub use atomic_str::AtomicStr;
-pub use backend::{Backend, BackendExt, SimpleBackend};
+pub use backend::{Backend, BackendExt, Resolved, SimpleBackend};
 pub use config::I18nConfig;
 pub use cow_str::CowStr;
+pub use currency::{format_currency, CurrencyDisplay};
+#[cfg(feature = "datetime")]
+pub use datetime::{format_datetime, DateTimeStyle, DateTimeValue};
+pub use link::{find_links, replace_links, Link, MAX_LINK_DEPTH};
+pub use list::{_ListItems, format_list, ListStyle};
+pub use locale::canonicalize_locale;
+pub use message_format::{
+    _format_icu_translation, format_icu_message, IcuError, IcuMessage, MESSAGE_FORMAT_DEFAULT,
+    MESSAGE_FORMAT_ICU,
+};
 pub use minify_key::{
     minify_key, MinifyKey, DEFAULT_MINIFY_KEY, DEFAULT_MINIFY_KEY_LEN, DEFAULT_MINIFY_KEY_PREFIX,
     DEFAULT_MINIFY_KEY_THRESH,
 };
+pub use negotiate::{
+    env_locales, negotiate_locale, parse_accept_language, posix_to_bcp47, UnsupportedLocale,
+};
+pub use number::{format_number, format_number_with_precision, NumberStyle};
+pub use placeholder::{extract_placeholders, parse_segments, Segment, Segments};
+pub use plural::{
+    ordinal_category, plural_category, PluralCategory, PluralOperands, PluralRuleType,
+};
+pub use pseudo::{pseudo_localize, PSEUDO_LOCALES, PSEUDO_LOCALE_ACCENTED, PSEUDO_LOCALE_BIDI};
+pub use relative::{format_duration, format_relative, SignedDuration};
+pub use style::{format_value, is_value_style, DATETIME_STYLES};
+pub use unit::{format_bytes, format_unit, Unit};
 
 type Locale = String;
 type Value = serde_json::Value;
@@ -46,13 +86,102 @@ pub fn load_locales<F: Fn(&str) -> bool>(
 ) -> HashMap<String, HashMap<String, String>> {
     let mut result: HashMap<String, HashMap<String, String>> = HashMap::new();
     let mut translations = HashMap::new();
+
+    walk_locale_files(locales_path, ignore_if, |_, trs| {
+        trs.into_iter().for_each(|(k, new_value)| {
+            translations
+                .entry(k)
+                .and_modify(|old_value| merge_value(old_value, &new_value))
+                .or_insert(new_value);
+        });
+    });
+
+    translations.iter().for_each(|(locale, trs)| {
+        result.insert(locale.to_string(), flatten_keys("", trs));
+    });
+
+    result
+}
+
+/// The format of a locale file written by [`dump_translations_v2`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocaleFormat {
+    /// YAML, the `.yml` and `.yaml` files.
+    Yaml,
+    /// JSON, the `.json` files.
+    Json,
+    /// TOML, the `.toml` files.
+    Toml,
+}
+
+/// Convert the translations of `key => locale => text` to the text of a v2 locale file in the `format`.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use rust_i18n_support::{dump_translations_v2, LocaleFormat};
+/// let trs = HashMap::from([(
+///     "hello".to_string(),
+///     HashMap::from([("en".to_string(), "Hello".to_string())]),
+/// )]);
+/// assert_eq!(
+///     dump_translations_v2(&trs, LocaleFormat::Yaml),
+///     "_version: 2\nhello:\n  en: Hello\n"
+/// );
+/// ```
+pub fn dump_translations_v2(
+    trs: &HashMap<String, HashMap<String, String>>,
+    format: LocaleFormat,
+) -> String {
+    let mut value = serde_json::Value::Object(serde_json::Map::new());
+    value["_version"] = serde_json::Value::Number(serde_json::Number::from(2));
+
+    for (key, val) in trs {
+        let mut obj = serde_json::Value::Object(serde_json::Map::new());
+        for (locale, text) in val {
+            obj[locale] = serde_json::Value::String(text.clone());
+        }
+        value[key] = obj;
+    }
+
+    match format {
+        LocaleFormat::Json => serde_json::to_string_pretty(&value).unwrap(),
+        LocaleFormat::Yaml => {
+            let text = serde_yml::to_string(&value).unwrap();
+            // Remove leading `---`
+            text.trim_start_matches("---").trim_start().to_string()
+        }
+        LocaleFormat::Toml => toml::to_string_pretty(&value).unwrap(),
+    }
+}
+
+/// Find the locale file that defines the translation of the key in the locale.
+///
+/// If more than one file defines it, returns the last one, which is the one `load_locales` uses.
+pub fn locate_translation(locales_path: &str, locale: &str, key: &str) -> Option<PathBuf> {
+    let mut found = None;
+    walk_locale_files(
+        locales_path,
+        |_| false,
+        |entry, trs| {
+            if let Some(trs) = trs.get(canonicalize_locale(locale).as_ref()) {
+                if flatten_keys("", trs).contains_key(key) {
+                    found = Some(entry.to_path_buf());
+                }
+            }
+        },
+    );
+    found
+}
+
+/// Find all locale files in the path, the `.yml`, `.yaml`, `.json` and `.toml` files in any sub-directory.
+pub fn locale_files(locales_path: &str) -> Vec<PathBuf> {
     let locales_path = match Path::new(locales_path).normalize() {
         Ok(p) => p,
         Err(e) => {
             if is_debug() {
                 println!("cargo:i18n-error={}", e);
             }
-            return result;
+            return vec![];
         }
     };
     let locales_path = match locales_path.as_path().to_str() {
@@ -61,7 +190,7 @@ pub fn load_locales<F: Fn(&str) -> bool>(
             if is_debug() {
                 println!("cargo:i18n-error=could not convert path");
             }
-            return result;
+            return vec![];
         }
     };
 
@@ -76,11 +205,22 @@ pub fn load_locales<F: Fn(&str) -> bool>(
         if is_debug() {
             println!("cargo:i18n-error=path not exists: {}", locales_path);
         }
-        return result;
+        return vec![];
     }
 
-    for entry in globwalk::glob(&path_pattern).expect("Failed to read glob pattern") {
-        let entry = entry.unwrap().into_path();
+    globwalk::glob(&path_pattern)
+        .expect("Failed to read glob pattern")
+        .map(|entry| entry.unwrap().into_path())
+        .collect()
+}
+
+// Parse all locale files in the path, and call `callback` with the translations of each file, by the canonical locales
+fn walk_locale_files<F, C>(locales_path: &str, ignore_if: F, mut callback: C)
+where
+    F: Fn(&str) -> bool,
+    C: FnMut(&Path, Translations),
+{
+    for entry in locale_files(locales_path) {
         if is_debug() {
             println!("cargo:i18n-load={}", &entry.display());
         }
@@ -92,7 +232,7 @@ pub fn load_locales<F: Fn(&str) -> bool>(
         let locale = entry
             .file_stem()
             .and_then(|s| s.to_str())
-            .and_then(|s| s.split('.').last())
+            .and_then(|s| s.split('.').next_back())
             .unwrap();
 
         let ext = entry.extension().and_then(|s| s.to_str()).unwrap();
@@ -107,20 +247,17 @@ pub fn load_locales<F: Fn(&str) -> bool>(
 
         let trs = parse_file(&content, ext, locale)
             .unwrap_or_else(|_| panic!("Parse file `{}` failed", entry.display()));
-
-        trs.into_iter().for_each(|(k, new_value)| {
-            translations
-                .entry(k)
-                .and_modify(|old_value| merge_value(old_value, &new_value))
-                .or_insert(new_value);
-        });
+        let trs = trs
+            .into_iter()
+            .fold(Translations::new(), |mut trs, (locale, value)| {
+                trs.entry(canonicalize_locale(&locale).into_owned())
+                    .and_modify(|old_value| merge_value(old_value, &value))
+                    .or_insert(value);
+                trs
+            });
+
+        callback(&entry, trs);
     }
-
-    translations.iter().for_each(|(locale, trs)| {
-        result.insert(locale.to_string(), flatten_keys("", trs));
-    });
-
-    result
 }
 
 // Parse Translations from file to support multiple formats
@@ -290,7 +427,7 @@ fn flatten_keys(prefix: &str, trs: &Value) -> HashMap<String, String> {
 
 #[cfg(test)]
 mod tests {
-    use super::{merge_value, parse_file};
+    use super::{locate_translation, merge_value, parse_file};
 
     #[test]
     fn test_merge_value() {
@@ -312,6 +449,18 @@ mod tests {
         assert_eq!(c["dar"]["b"], "21");
     }
 
+    #[test]
+    fn test_locate_translation() {
+        let path = locate_translation("../../tests/locales", "en", "messages.hello").unwrap();
+        assert!(path.ends_with("en.yml"));
+        let path = locate_translation("../../tests/locales", "ja", "nested_locale_test").unwrap();
+        assert!(path.ends_with("v2.yml"));
+        assert_eq!(
+            locate_translation("../../tests/locales", "en", "not.exists"),
+            None
+        );
+    }
+
     #[test]
     fn test_parse_file_in_yaml() {
         let content = "foo: Foo\nbar: Bar";
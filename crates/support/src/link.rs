@@ -0,0 +1,162 @@
+use std::ops::Range;
+
+/// The max depth of the nested linked messages, the deeper links are kept as-is.
+pub const MAX_LINK_DEPTH: usize = 16;
+
+/// A `@:key` (or `@:{key}`) link to another translation in a translation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link<'a> {
+    /// The byte range of the link in the translation, including the `@:`.
+    pub range: Range<usize>,
+    /// The key of the linked translation.
+    pub key: &'a str,
+}
+
+/// Find the links to other translations in a translation.
+///
+/// - `@:brand.name` links to the `brand.name` key, the key is made of ASCII letters, digits, `_`, `-` and `.`,
+///   and the trailing `.` are not a part of the key, for example `Welcome to @:brand.name.`.
+/// - `@:{brand.name}` links to the `brand.name` key, for the keys followed by other letters.
+/// - `@@:` is a literal `@:`, not a link, it's replaced with `@:` by [`replace_links`].
+///
+/// ```
+/// # use rust_i18n_support::find_links;
+/// let links = find_links("Welcome to @:brand.name, by @:{brand.company}s, not @@:brand.slogan.")
+///     .iter()
+///     .map(|link| link.key)
+///     .collect::<Vec<_>>();
+/// assert_eq!(links, vec!["brand.name", "brand.company"]);
+/// ```
+pub fn find_links(input: &str) -> Vec<Link<'_>> {
+    let mut result = vec![];
+    let mut pos = 0;
+    while let Some(offset) = input[pos..].find("@:") {
+        let start = pos + offset;
+        let key_start = start + 2;
+        // The escaped `@@:`
+        if input[..start].ends_with('@') {
+            pos = key_start;
+            continue;
+        }
+        let rest = &input[key_start..];
+        let (key, end) = if let Some(braced) = rest.strip_prefix('{') {
+            match braced.find('}') {
+                Some(len) => (&braced[..len], key_start + len + 2),
+                None => ("", key_start),
+            }
+        } else {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')))
+                .unwrap_or(rest.len());
+            let key = rest[..len].trim_end_matches('.');
+            (key, key_start + key.len())
+        };
+
+        if key.is_empty() {
+            pos = key_start;
+            continue;
+        }
+        result.push(Link {
+            range: start..end,
+            key,
+        });
+        pos = end;
+    }
+    result
+}
+
+/// Replace the links in a translation with the result of `lookup`, the links without result are kept as-is,
+/// and the escaped `@@:` are replaced with `@:`.
+///
+/// ```
+/// # use rust_i18n_support::replace_links;
+/// let output = replace_links("Welcome to @:brand.name! Mention with @@:name.", |key| {
+///     (key == "brand.name").then(|| "Rust I18n".to_string())
+/// });
+/// assert_eq!(output, "Welcome to Rust I18n! Mention with @:name.");
+/// ```
+pub fn replace_links(input: &str, mut lookup: impl FnMut(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut prev_end = 0;
+    for link in find_links(input) {
+        if let Some(value) = lookup(link.key) {
+            push_unescaped(&mut output, &input[prev_end..link.range.start]);
+            output.push_str(&value);
+            prev_end = link.range.end;
+        }
+    }
+    push_unescaped(&mut output, &input[prev_end..]);
+    output
+}
+
+/// Push the text with the escaped `@@:` replaced with `@:`.
+fn push_unescaped(output: &mut String, text: &str) {
+    let mut rest = text;
+    while let Some(pos) = rest.find("@@:") {
+        output.push_str(&rest[..pos + 1]);
+        output.push(':');
+        rest = &rest[pos + 3..];
+    }
+    output.push_str(rest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(input: &str) -> Vec<&str> {
+        find_links(input).iter().map(|link| link.key).collect()
+    }
+
+    #[test]
+    fn test_find_links() {
+        assert_eq!(keys(""), Vec::<&str>::new());
+        assert_eq!(keys("Hello"), Vec::<&str>::new());
+        assert_eq!(keys("@:brand"), vec!["brand"]);
+        assert_eq!(keys("Welcome to @:brand.name."), vec!["brand.name"]);
+        assert_eq!(keys("Welcome to @:brand.name..."), vec!["brand.name"]);
+        assert_eq!(keys("@:a.b-c_d,@:e"), vec!["a.b-c_d", "e"]);
+        assert_eq!(
+            keys("@:{brand.name}s @:{with space}"),
+            vec!["brand.name", "with space"]
+        );
+        assert_eq!(keys("@:@:a"), vec!["a"]);
+        // Not links
+        assert_eq!(keys("user@:"), Vec::<&str>::new());
+        assert_eq!(keys("@: @:. @:{} @:{unclosed"), Vec::<&str>::new());
+        assert_eq!(keys("@brand"), Vec::<&str>::new());
+        // Escaped
+        assert_eq!(keys("@@:brand @@:{brand}"), Vec::<&str>::new());
+        assert_eq!(keys("@@:brand @:name"), vec!["name"]);
+
+        let links = find_links("Hi @:{a}, @:b.");
+        assert_eq!(links[0].range, 3..8);
+        assert_eq!(links[1].range, 10..13);
+    }
+
+    #[test]
+    fn test_replace_links() {
+        let lookup = |key: &str| match key {
+            "brand.name" => Some("Rust I18n".to_string()),
+            "brand" => Some("Brand".to_string()),
+            _ => None,
+        };
+        assert_eq!(replace_links("Hello", lookup), "Hello");
+        assert_eq!(
+            replace_links("Welcome to @:brand.name.", lookup),
+            "Welcome to Rust I18n."
+        );
+        assert_eq!(replace_links("@:{brand}s", lookup), "Brands");
+        assert_eq!(replace_links("@:brand@:brand", lookup), "BrandBrand");
+        assert_eq!(
+            replace_links("@:unknown and @:brand", lookup),
+            "@:unknown and Brand"
+        );
+        assert_eq!(replace_links("@@:brand", lookup), "@:brand");
+        assert_eq!(
+            replace_links("@:brand, @@:brand and @@@:brand", lookup),
+            "Brand, @:brand and @@:brand"
+        );
+        assert_eq!(replace_links("mail@@:{x}", lookup), "mail@:{x}");
+    }
+}
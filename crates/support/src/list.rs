@@ -0,0 +1,266 @@
+use std::fmt::Display;
+
+use crate::locale::locale_entry;
+
+/// The style of a locale-aware list, selected by `%{name:list}` in a translation or `name = items : {list}` in `t!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListStyle {
+    /// `list`, the conjunction of the items, such as `A, B, and C`.
+    And,
+    /// `list_or`, the disjunction of the items, such as `A, B, or C`.
+    Or,
+    /// `list_unit`, the items of a measurement, such as `3 ft, 7 in`.
+    Unit,
+}
+
+impl ListStyle {
+    /// Parse the style name, returns `None` if it's not a list style.
+    pub fn parse(style: &str) -> Option<Self> {
+        match style {
+            "list" => Some(Self::And),
+            "list_or" => Some(Self::Or),
+            "list_unit" => Some(Self::Unit),
+            _ => None,
+        }
+    }
+}
+
+/// The CLDR list patterns, `{0}` and `{1}` are replaced with the item and the rest of the list.
+struct ListPatterns {
+    /// The pattern of 2 items.
+    pair: &'static str,
+    /// The pattern of the first item.
+    start: &'static str,
+    /// The pattern of the middle items.
+    middle: &'static str,
+    /// The pattern of the last 2 items.
+    end: &'static str,
+}
+
+/// The list patterns of a locale, of `and`, `or` and `unit`.
+type ListSymbols = [ListPatterns; 3];
+
+const fn patterns(pair: &'static str, middle: &'static str, end: &'static str) -> ListPatterns {
+    ListPatterns {
+        pair,
+        start: middle,
+        middle,
+        end,
+    }
+}
+
+/// The patterns with the same pair and end, such as `{0} und {1}`.
+const fn joined(middle: &'static str, end: &'static str) -> ListPatterns {
+    patterns(end, middle, end)
+}
+
+const EN: ListSymbols = [
+    patterns("{0} and {1}", "{0}, {1}", "{0}, and {1}"),
+    patterns("{0} or {1}", "{0}, {1}", "{0}, or {1}"),
+    joined("{0}, {1}", "{0}, {1}"),
+];
+
+/// The list patterns of the locales, from CLDR, the other locales use [`EN`].
+const LOCALE_SYMBOLS: &[(&str, ListSymbols)] = &[
+    (
+        "de",
+        [
+            joined("{0}, {1}", "{0} und {1}"),
+            joined("{0}, {1}", "{0} oder {1}"),
+            joined("{0}, {1}", "{0} und {1}"),
+        ],
+    ),
+    (
+        "en-GB",
+        [
+            joined("{0}, {1}", "{0} and {1}"),
+            joined("{0}, {1}", "{0} or {1}"),
+            joined("{0}, {1}", "{0}, {1}"),
+        ],
+    ),
+    (
+        "es",
+        [
+            joined("{0}, {1}", "{0} y {1}"),
+            joined("{0}, {1}", "{0} o {1}"),
+            joined("{0}, {1}", "{0} y {1}"),
+        ],
+    ),
+    (
+        "fr",
+        [
+            joined("{0}, {1}", "{0} et {1}"),
+            joined("{0}, {1}", "{0} ou {1}"),
+            joined("{0}, {1}", "{0} et {1}"),
+        ],
+    ),
+    (
+        "it",
+        [
+            joined("{0}, {1}", "{0} e {1}"),
+            joined("{0}, {1}", "{0} o {1}"),
+            joined("{0}, {1}", "{0} e {1}"),
+        ],
+    ),
+    (
+        "ja",
+        [
+            joined("{0}、{1}", "{0}、{1}"),
+            patterns("{0}または{1}", "{0}、{1}", "{0}、または{1}"),
+            joined("{0} {1}", "{0} {1}"),
+        ],
+    ),
+    (
+        "ko",
+        [
+            joined("{0}, {1}", "{0} 및 {1}"),
+            joined("{0}, {1}", "{0} 또는 {1}"),
+            joined("{0} {1}", "{0} {1}"),
+        ],
+    ),
+    (
+        "pt",
+        [
+            joined("{0}, {1}", "{0} e {1}"),
+            joined("{0}, {1}", "{0} ou {1}"),
+            joined("{0}, {1}", "{0} e {1}"),
+        ],
+    ),
+    (
+        "ru",
+        [
+            joined("{0}, {1}", "{0} и {1}"),
+            joined("{0}, {1}", "{0} или {1}"),
+            joined("{0} {1}", "{0} {1}"),
+        ],
+    ),
+    (
+        "zh",
+        [
+            joined("{0}、{1}", "{0}和{1}"),
+            joined("{0}、{1}", "{0}或{1}"),
+            joined("{0}{1}", "{0}{1}"),
+        ],
+    ),
+];
+
+/// Find the symbols of the locale, or of its language, such as `zh-CN` -> `zh`.
+fn list_symbols(locale: &str) -> &'static ListSymbols {
+    locale_entry(LOCALE_SYMBOLS, locale).unwrap_or(&EN)
+}
+
+/// Join the items into a list in the locale, with the CLDR list patterns of the locale.
+///
+/// ```
+/// # use rust_i18n_support::{format_list, ListStyle};
+/// assert_eq!(format_list("en", &["A", "B", "C"], ListStyle::And), "A, B, and C");
+/// assert_eq!(format_list("zh", &["A", "B", "C"], ListStyle::And), "A、B和C");
+/// assert_eq!(format_list("es", &["A", "B", "C"], ListStyle::And), "A, B y C");
+/// ```
+pub fn format_list<S: AsRef<str>>(locale: &str, items: &[S], style: ListStyle) -> String {
+    let symbols = list_symbols(locale);
+    let patterns = match style {
+        ListStyle::And => &symbols[0],
+        ListStyle::Or => &symbols[1],
+        ListStyle::Unit => &symbols[2],
+    };
+    // Replace the placeholders at once, so the `{1}` in an item is kept
+    let apply = |pattern: &str, item: &str, rest: &str| {
+        let (before, after) = pattern.split_once("{0}").unwrap_or((pattern, ""));
+        let (between, after) = after.split_once("{1}").unwrap_or((after, ""));
+        format!("{}{}{}{}{}", before, item, between, rest, after)
+    };
+
+    match items {
+        [] => String::new(),
+        [item] => item.as_ref().to_string(),
+        [first, second] => apply(patterns.pair, first.as_ref(), second.as_ref()),
+        [first, middle @ .., last_two, last] => {
+            let mut list = apply(patterns.end, last_two.as_ref(), last.as_ref());
+            for item in middle.iter().rev() {
+                list = apply(patterns.middle, item.as_ref(), &list);
+            }
+            apply(patterns.start, first.as_ref(), &list)
+        }
+    }
+}
+
+/// Get the items of a `name = items : {list}` argument of `t!`, from a reference to the collection,
+/// such as a `Vec`, a slice or an array of any [`Display`] type, so the collection is not moved.
+#[doc(hidden)]
+pub trait _ListItems {
+    fn _list_items(&self) -> Vec<String>;
+}
+
+impl<T: ?Sized> _ListItems for T
+where
+    for<'a> &'a T: IntoIterator,
+    for<'a> <&'a T as IntoIterator>::Item: Display,
+{
+    fn _list_items(&self) -> Vec<String> {
+        self.into_iter().map(|item| item.to_string()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_list() {
+        let format = |locale, items: &[&str], style| format_list(locale, items, style);
+        assert_eq!(format("en", &[], ListStyle::And), "");
+        assert_eq!(format("en", &["{1}", "{0}"], ListStyle::And), "{1} and {0}");
+        assert_eq!(format("en", &["A"], ListStyle::And), "A");
+        assert_eq!(format("en", &["A", "B"], ListStyle::And), "A and B");
+        assert_eq!(
+            format("en", &["A", "B", "C"], ListStyle::And),
+            "A, B, and C"
+        );
+        assert_eq!(
+            format("en-US", &["A", "B", "C", "D"], ListStyle::And),
+            "A, B, C, and D"
+        );
+        assert_eq!(format("en", &["A", "B", "C"], ListStyle::Or), "A, B, or C");
+        assert_eq!(
+            format("en", &["3 ft", "7 in"], ListStyle::Unit),
+            "3 ft, 7 in"
+        );
+        assert_eq!(
+            format("en-GB", &["A", "B", "C"], ListStyle::And),
+            "A, B and C"
+        );
+        assert_eq!(format("de", &["A", "B", "C"], ListStyle::And), "A, B und C");
+        assert_eq!(format("es", &["A", "B", "C"], ListStyle::And), "A, B y C");
+        assert_eq!(format("fr", &["A", "B"], ListStyle::Or), "A ou B");
+        assert_eq!(format("ru", &["A", "B", "C"], ListStyle::And), "A, B и C");
+        assert_eq!(format("zh-CN", &["A", "B", "C"], ListStyle::And), "A、B和C");
+        assert_eq!(format("zh", &["A", "B", "C"], ListStyle::Or), "A、B或C");
+        assert_eq!(format("ja", &["A", "B", "C"], ListStyle::And), "A、B、C");
+        assert_eq!(
+            format("ja", &["A", "B", "C"], ListStyle::Or),
+            "A、B、またはC"
+        );
+        assert_eq!(format("ko", &["A", "B", "C"], ListStyle::And), "A, B 및 C");
+    }
+
+    #[test]
+    fn test_list_items() {
+        let names = vec!["Alice".to_string(), "Bob\u{1F}".to_string()];
+        assert_eq!(names._list_items(), names);
+        assert_eq!(names[..1]._list_items(), vec!["Alice"]);
+        assert_eq!([1, 2]._list_items(), vec!["1", "2"]);
+        assert_eq!(
+            std::collections::BTreeSet::from(["b", "a"])._list_items(),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test]
+    fn test_list_style() {
+        assert_eq!(ListStyle::parse("list"), Some(ListStyle::And));
+        assert_eq!(ListStyle::parse("list_or"), Some(ListStyle::Or));
+        assert_eq!(ListStyle::parse("list_unit"), Some(ListStyle::Unit));
+        assert_eq!(ListStyle::parse("lists"), None);
+    }
+}
@@ -0,0 +1,198 @@
+use std::borrow::Cow;
+
+/// The deprecated ISO 639 language codes and their replacements.
+const DEPRECATED_LANGUAGES: &[(&str, &str)] = &[
+    ("in", "id"),
+    ("iw", "he"),
+    ("ji", "yi"),
+    ("jw", "jv"),
+    ("mo", "ro"),
+];
+
+/// Canonicalize a BCP 47 language tag, so the different spellings of a locale are the same locale.
+///
+/// - `_` is replaced with `-`, such as `en_US` -> `en-US`.
+/// - The language is in lowercase, and the deprecated codes are replaced, such as `iw` -> `he`.
+/// - The script is in title case and the region is in uppercase, such as `zh-hant-tw` -> `zh-Hant-TW`.
+/// - The variants, extensions and private use subtags are in lowercase.
+///
+/// The input is borrowed if it is already canonical.
+///
+/// ```
+/// # use rust_i18n_support::canonicalize_locale;
+/// assert_eq!(canonicalize_locale("en_us"), "en-US");
+/// assert_eq!(canonicalize_locale("ZH-hant-tw"), "zh-Hant-TW");
+/// assert_eq!(canonicalize_locale("iw"), "he");
+/// ```
+pub fn canonicalize_locale(locale: &str) -> Cow<'_, str> {
+    let trimmed = locale.trim();
+    let is_canonical = trimmed.len() == locale.len()
+        && !locale.contains('_')
+        && subtags(locale).all(|(subtag, case)| match case {
+            Case::Language => {
+                subtag.chars().all(|c| !c.is_ascii_uppercase())
+                    && !DEPRECATED_LANGUAGES
+                        .iter()
+                        .any(|(deprecated, _)| *deprecated == subtag)
+            }
+            Case::Lower => subtag.chars().all(|c| !c.is_ascii_uppercase()),
+            Case::Upper => subtag.chars().all(|c| !c.is_ascii_lowercase()),
+            Case::Title => {
+                let mut chars = subtag.chars();
+                chars.next().is_some_and(|c| !c.is_ascii_lowercase())
+                    && chars.all(|c| !c.is_ascii_uppercase())
+            }
+        });
+    if is_canonical {
+        return Cow::Borrowed(locale);
+    }
+
+    let mut result = String::with_capacity(trimmed.len());
+    for (i, (subtag, case)) in subtags(trimmed).enumerate() {
+        if i > 0 {
+            result.push('-');
+        }
+        match case {
+            Case::Language => {
+                let language = subtag.to_ascii_lowercase();
+                let language = DEPRECATED_LANGUAGES
+                    .iter()
+                    .find(|(deprecated, _)| *deprecated == language)
+                    .map_or(language.as_str(), |(_, replacement)| replacement);
+                result.push_str(language);
+            }
+            Case::Lower => result.push_str(&subtag.to_ascii_lowercase()),
+            Case::Upper => result.push_str(&subtag.to_ascii_uppercase()),
+            Case::Title => {
+                let (first, rest) = subtag.split_at(1);
+                result.push_str(&first.to_ascii_uppercase());
+                result.push_str(&rest.to_ascii_lowercase());
+            }
+        }
+    }
+    Cow::Owned(result)
+}
+
+/// Find the value of the locale, or of its parent locales, such as `zh-Hant-TW` -> `zh-Hant` -> `zh`.
+pub(crate) fn find_locale<T>(locale: &str, mut find: impl FnMut(&str) -> Option<T>) -> Option<T> {
+    let locale = canonicalize_locale(locale);
+    let mut locale = locale.as_ref();
+    loop {
+        if let Some(value) = find(locale) {
+            return Some(value);
+        }
+        locale = &locale[..locale.rfind('-')?];
+    }
+}
+
+/// Find the entry of the locale in the table of the locale data, or of its parent locales, see [`find_locale`].
+pub(crate) fn locale_entry<'a, T>(table: &'a [(&str, T)], locale: &str) -> Option<&'a T> {
+    find_locale(locale, |locale| {
+        table
+            .iter()
+            .find(|(l, _)| *l == locale)
+            .map(|(_, entry)| entry)
+    })
+}
+
+/// The canonical case of a subtag.
+enum Case {
+    Language,
+    Lower,
+    Upper,
+    Title,
+}
+
+/// Split the language tag into the subtags with their canonical case.
+fn subtags(locale: &str) -> impl Iterator<Item = (&str, Case)> {
+    // After a singleton, such as `u` or `x`, all subtags are in lowercase
+    let mut in_extension = false;
+    locale
+        .split(['-', '_'])
+        .enumerate()
+        .map(move |(i, subtag)| {
+            let is_alphabetic = subtag.chars().all(|c| c.is_ascii_alphabetic());
+            let case = if i == 0 {
+                in_extension = subtag.len() == 1;
+                Case::Language
+            } else if in_extension || subtag.len() == 1 {
+                in_extension = true;
+                Case::Lower
+            } else if i == 1 && subtag.len() == 4 && is_alphabetic {
+                Case::Title
+            } else if subtag.len() == 2 && is_alphabetic {
+                Case::Upper
+            } else {
+                Case::Lower
+            };
+            (subtag, case)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonicalize_locale() {
+        assert_eq!(canonicalize_locale(""), "");
+        assert_eq!(canonicalize_locale("en"), "en");
+        assert_eq!(canonicalize_locale("EN"), "en");
+        assert_eq!(canonicalize_locale("en-us"), "en-US");
+        assert_eq!(canonicalize_locale("en_US"), "en-US");
+        assert_eq!(canonicalize_locale(" en-US "), "en-US");
+        assert_eq!(canonicalize_locale("zh_hans_cn"), "zh-Hans-CN");
+        assert_eq!(canonicalize_locale("SR-LATN"), "sr-Latn");
+        assert_eq!(canonicalize_locale("es-419"), "es-419");
+        assert_eq!(canonicalize_locale("de-DE-1996"), "de-DE-1996");
+        assert_eq!(canonicalize_locale("sl-ROZAJ-BISKE"), "sl-rozaj-biske");
+        // Deprecated codes
+        assert_eq!(canonicalize_locale("iw"), "he");
+        assert_eq!(canonicalize_locale("iw_IL"), "he-IL");
+        assert_eq!(canonicalize_locale("in-ID"), "id-ID");
+        assert_eq!(canonicalize_locale("ji"), "yi");
+        assert_eq!(canonicalize_locale("mo"), "ro");
+        // Extensions and private use
+        assert_eq!(
+            canonicalize_locale("en-US-U-CA-Buddhist"),
+            "en-US-u-ca-buddhist"
+        );
+        assert_eq!(
+            canonicalize_locale("zh-Hant-CN-x-Private1-AB"),
+            "zh-Hant-CN-x-private1-ab"
+        );
+        assert_eq!(canonicalize_locale("x-Whatever"), "x-whatever");
+    }
+
+    #[test]
+    fn test_canonicalize_locale_borrowed() {
+        assert!(matches!(canonicalize_locale("en"), Cow::Borrowed(_)));
+        assert!(matches!(
+            canonicalize_locale("zh-Hant-TW"),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(canonicalize_locale("zh-CN"), Cow::Borrowed(_)));
+        assert!(matches!(
+            canonicalize_locale("en-US-u-ca-buddhist"),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(canonicalize_locale(""), Cow::Borrowed(_)));
+        assert!(matches!(canonicalize_locale("zh_CN"), Cow::Owned(_)));
+        assert!(matches!(canonicalize_locale("zh-cn"), Cow::Owned(_)));
+        assert!(matches!(canonicalize_locale("zh-hant"), Cow::Owned(_)));
+        assert!(matches!(canonicalize_locale("iw"), Cow::Owned(_)));
+        assert!(matches!(canonicalize_locale(" en"), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_locale_entry() {
+        let table = [("zh", 1), ("zh-Hant", 2), ("pt-BR", 3)];
+        assert_eq!(locale_entry(&table, "zh"), Some(&1));
+        assert_eq!(locale_entry(&table, "zh-CN"), Some(&1));
+        assert_eq!(locale_entry(&table, "zh_hant_tw"), Some(&2));
+        assert_eq!(locale_entry(&table, "pt-BR"), Some(&3));
+        assert_eq!(locale_entry(&table, "pt"), None);
+        assert_eq!(locale_entry(&table, "en"), None);
+        assert_eq!(locale_entry(&table, ""), None);
+    }
+}
@@ -0,0 +1,716 @@
+use crate::plural::{PluralCategory, PluralOperands, PluralRuleType};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// The default message format, with `%{name}` placeholders.
+pub const MESSAGE_FORMAT_DEFAULT: &str = "default";
+
+/// The ICU MessageFormat, with `{name}`, `{count, plural, ...}` and `{gender, select, ...}` arguments.
+pub const MESSAGE_FORMAT_ICU: &str = "icu";
+
+/// The parsed translations by their text, `None` if the text is not a valid ICU message.
+static PARSED_TRANSLATIONS: Lazy<RwLock<HashMap<String, Option<Arc<IcuMessage>>>>> =
+    Lazy::new(Default::default);
+
+/// An error of parsing an ICU message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcuError {
+    /// The description of the error.
+    pub message: String,
+    /// The 1-based line of the error in the message.
+    pub line: usize,
+    /// The 1-based column (in characters) of the error in the message.
+    pub column: usize,
+}
+
+impl fmt::Display for IcuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}:{}", self.message, self.line, self.column)
+    }
+}
+
+impl std::error::Error for IcuError {}
+
+/// A parsed ICU message.
+///
+/// Only the formatting subset is supported:
+///
+/// - Simple arguments: `{name}`.
+/// - Formatted arguments: `{amount, number}`, the type and style are ignored and the value is used as-is.
+/// - Plural arguments: `{count, plural, offset:1 =0 {none} one {# item} other {# items}}`.
+/// - Ordinal arguments: `{place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}`.
+/// - Select arguments: `{gender, select, female {she} male {he} other {they}}`.
+/// - Quoting: `''` is a literal apostrophe, `'{...}'` is literal text.
+///
+/// https://unicode-org.github.io/icu/userguide/format_parse/messages/
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcuMessage {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Text(String),
+    /// The `#` in a plural case, replaced by the number minus the offset.
+    Pound,
+    Argument(String),
+    Plural {
+        name: String,
+        rule_type: PluralRuleType,
+        offset: f64,
+        cases: Vec<(Selector, IcuMessage)>,
+    },
+    Select {
+        name: String,
+        cases: Vec<(String, IcuMessage)>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Exact(f64),
+    Category(PluralCategory),
+}
+
+impl IcuMessage {
+    /// Parse an ICU message.
+    pub fn parse(input: &str) -> Result<Self, IcuError> {
+        let mut parser = Parser {
+            input,
+            chars: input.char_indices().collect(),
+            pos: 0,
+        };
+        let message = parser.parse_message(false)?;
+        match parser.peek() {
+            None => Ok(message),
+            Some(_) => Err(parser.error("Unexpected `}`")),
+        }
+    }
+
+    /// Format the message with the arguments, using the plural rules of the locale.
+    ///
+    /// Arguments without values are kept as `{name}`.
+    pub fn format(&self, locale: &str, patterns: &[&str], values: &[String]) -> String {
+        let mut output = String::new();
+        self.format_into(&mut output, locale, patterns, values, None);
+        output
+    }
+
+    /// The names of all arguments in the message, including the plural and select arguments.
+    pub fn arguments(&self) -> Vec<&str> {
+        let mut result = vec![];
+        self.collect_arguments(&mut result);
+        result
+    }
+
+    fn collect_arguments<'a>(&'a self, result: &mut Vec<&'a str>) {
+        for part in &self.parts {
+            let (name, cases) = match part {
+                Part::Text(_) | Part::Pound => continue,
+                Part::Argument(name) => (name, vec![]),
+                Part::Plural { name, cases, .. } => {
+                    (name, cases.iter().map(|(_, message)| message).collect())
+                }
+                Part::Select { name, cases } => {
+                    (name, cases.iter().map(|(_, message)| message).collect())
+                }
+            };
+            if !result.contains(&name.as_str()) {
+                result.push(name);
+            }
+            cases
+                .into_iter()
+                .for_each(|message| message.collect_arguments(result));
+        }
+    }
+
+    fn format_into(
+        &self,
+        output: &mut String,
+        locale: &str,
+        patterns: &[&str],
+        values: &[String],
+        number: Option<&str>,
+    ) {
+        let value_of = |name: &str| {
+            patterns
+                .iter()
+                .zip(values.iter())
+                .find(|(pattern, _)| **pattern == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => output.push_str(text),
+                Part::Pound => output.push_str(number.unwrap_or("#")),
+                Part::Argument(name) => match value_of(name) {
+                    Some(value) => output.push_str(value),
+                    None => {
+                        output.push('{');
+                        output.push_str(name);
+                        output.push('}');
+                    }
+                },
+                Part::Plural {
+                    name,
+                    rule_type,
+                    offset,
+                    cases,
+                } => {
+                    let value = value_of(name);
+                    let number = value.and_then(|v| v.trim().parse::<f64>().ok());
+                    let pound = match (value, number) {
+                        (Some(value), _) if *offset == 0.0 => value.to_string(),
+                        (_, Some(number)) => format_number(number - offset),
+                        _ => "#".to_string(),
+                    };
+                    let category = PluralOperands::parse(&pound)
+                        .map_or(PluralCategory::Other, |operands| {
+                            rule_type.category(locale, &operands)
+                        });
+                    let case = number
+                        .and_then(|number| {
+                            cases.iter().find(|(selector, _)| {
+                                matches!(selector, Selector::Exact(exact) if *exact == number)
+                            })
+                        })
+                        .or_else(|| {
+                            cases.iter().find(|(selector, _)| {
+                                matches!(selector, Selector::Category(c) if *c == category)
+                            })
+                        })
+                        .or_else(|| {
+                            cases.iter().find(|(selector, _)| {
+                                matches!(selector, Selector::Category(PluralCategory::Other))
+                            })
+                        });
+                    if let Some((_, message)) = case {
+                        message.format_into(output, locale, patterns, values, Some(&pound));
+                    }
+                }
+                Part::Select { name, cases } => {
+                    let value = value_of(name).unwrap_or("other");
+                    let case = cases
+                        .iter()
+                        .find(|(key, _)| key == value)
+                        .or_else(|| cases.iter().find(|(key, _)| key == "other"));
+                    if let Some((_, message)) = case {
+                        message.format_into(output, locale, patterns, values, number);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Format a number without a trailing `.0` for integers.
+fn format_number(number: f64) -> String {
+    if number.fract() == 0.0 && number.abs() < 1e15 {
+        format!("{}", number as i64)
+    } else {
+        format!("{}", number)
+    }
+}
+
+/// Format an ICU message, or returns the input as-is if it's not a valid ICU message.
+///
+/// ```
+/// # use rust_i18n_support::format_icu_message;
+/// let input = "{count, plural, one {# file} other {# files}}";
+/// assert_eq!(format_icu_message("en", input, &["count"], &["1".into()]), "1 file");
+/// assert_eq!(format_icu_message("en", input, &["count"], &["3".into()]), "3 files");
+/// ```
+pub fn format_icu_message(
+    locale: &str,
+    input: &str,
+    patterns: &[&str],
+    values: &[String],
+) -> String {
+    match IcuMessage::parse(input) {
+        Ok(message) => message.format(locale, patterns, values),
+        Err(_) => input.to_string(),
+    }
+}
+
+/// Format a translation as an ICU message like [`format_icu_message`], the message is parsed only once.
+///
+/// The parsed messages are cached by their text, so it's only for the translations, which are a fixed set,
+/// not for the messages from the call sites, such as the keys of missing translations.
+#[doc(hidden)]
+pub fn _format_icu_translation(
+    locale: &str,
+    input: &str,
+    patterns: &[&str],
+    values: &[String],
+) -> String {
+    let cached = PARSED_TRANSLATIONS.read().unwrap().get(input).cloned();
+    let message = match cached {
+        Some(message) => message,
+        None => {
+            let message = IcuMessage::parse(input).ok().map(Arc::new);
+            PARSED_TRANSLATIONS
+                .write()
+                .unwrap()
+                .insert(input.to_string(), message.clone());
+            message
+        }
+    };
+    match message {
+        Some(message) => message.format(locale, patterns, values),
+        None => input.to_string(),
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Vec<(usize, char)>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).map(|(_, c)| *c)
+    }
+
+    /// Returns the byte offset of the current position.
+    fn offset(&self) -> usize {
+        self.chars
+            .get(self.pos)
+            .map_or(self.input.len(), |(offset, _)| *offset)
+    }
+
+    fn error(&self, message: impl Into<String>) -> IcuError {
+        let consumed = &self.input[..self.offset()];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed
+            .rsplit('\n')
+            .next()
+            .map_or(0, |s| s.chars().count())
+            + 1;
+        IcuError {
+            message: message.into(),
+            line,
+            column,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), IcuError> {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected `{}`", expected)))
+        }
+    }
+
+    /// Parse a message until the end of input or an unmatched `}`.
+    fn parse_message(&mut self, in_plural: bool) -> Result<IcuMessage, IcuError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    self.pos += 1;
+                    parts.push(self.parse_argument(in_plural)?);
+                }
+                '}' => break,
+                '#' if in_plural => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    self.pos += 1;
+                    parts.push(Part::Pound);
+                }
+                '\'' => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some('\'') => {
+                            self.pos += 1;
+                            text.push('\'');
+                        }
+                        Some('{' | '}') => self.parse_quoted(&mut text),
+                        Some('#') if in_plural => self.parse_quoted(&mut text),
+                        _ => text.push('\''),
+                    }
+                }
+                c => {
+                    self.pos += 1;
+                    text.push(c);
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+
+        Ok(IcuMessage { parts })
+    }
+
+    /// Parse a quoted literal text after the opening apostrophe, until the closing apostrophe or the end.
+    fn parse_quoted(&mut self, text: &mut String) {
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if c == '\'' {
+                if self.peek() == Some('\'') {
+                    self.pos += 1;
+                    text.push('\'');
+                } else {
+                    return;
+                }
+            } else {
+                text.push(c);
+            }
+        }
+    }
+
+    fn parse_identifier(&mut self) -> Result<String, IcuError> {
+        self.skip_whitespace();
+        let mut ident = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_alphanumeric() || c == '_' || c == '-' {
+                ident.push(c);
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        if ident.is_empty() {
+            return Err(self.error("Expected an argument name"));
+        }
+        self.skip_whitespace();
+        Ok(ident)
+    }
+
+    /// Parse an argument after the opening `{`, including the closing `}`.
+    fn parse_argument(&mut self, in_plural: bool) -> Result<Part, IcuError> {
+        let name = self.parse_identifier()?;
+        match self.peek() {
+            Some('}') => {
+                self.pos += 1;
+                return Ok(Part::Argument(name));
+            }
+            Some(',') => self.pos += 1,
+            _ => return Err(self.error("Expected `,` or `}` after the argument name")),
+        }
+
+        let kind = self.parse_identifier()?;
+        let part = match kind.as_str() {
+            "plural" | "selectordinal" => {
+                self.expect(',')?;
+                let rule_type = if kind == "plural" {
+                    PluralRuleType::Cardinal
+                } else {
+                    PluralRuleType::Ordinal
+                };
+                self.parse_plural(name, rule_type)?
+            }
+            "select" => {
+                self.expect(',')?;
+                self.parse_select(name, in_plural)?
+            }
+            _ => {
+                // Skip the style, e.g. `short` in `{d, date, short}`
+                if self.peek() == Some(',') {
+                    self.pos += 1;
+                    while let Some(c) = self.peek() {
+                        if c == '}' {
+                            break;
+                        }
+                        if c == '{' {
+                            return Err(self.error("Unexpected `{` in the argument style"));
+                        }
+                        self.pos += 1;
+                    }
+                }
+                Part::Argument(name)
+            }
+        };
+
+        self.skip_whitespace();
+        self.expect('}')?;
+        Ok(part)
+    }
+
+    fn parse_case_message(&mut self, in_plural: bool) -> Result<IcuMessage, IcuError> {
+        self.skip_whitespace();
+        self.expect('{')?;
+        let message = self.parse_message(in_plural)?;
+        self.expect('}')?;
+        self.skip_whitespace();
+        Ok(message)
+    }
+
+    fn parse_plural(&mut self, name: String, rule_type: PluralRuleType) -> Result<Part, IcuError> {
+        self.skip_whitespace();
+        let mut offset = 0.0;
+        if self.input[self.offset()..].starts_with("offset:") {
+            self.pos += "offset:".len();
+            self.skip_whitespace();
+            offset = self.parse_number()?;
+        }
+
+        let mut cases = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let selector = match self.peek() {
+                Some('}') | None => break,
+                Some('=') => {
+                    self.pos += 1;
+                    Selector::Exact(self.parse_number()?)
+                }
+                Some(_) => {
+                    let keyword = self.parse_identifier()?;
+                    let category = match keyword.as_str() {
+                        "zero" => PluralCategory::Zero,
+                        "one" => PluralCategory::One,
+                        "two" => PluralCategory::Two,
+                        "few" => PluralCategory::Few,
+                        "many" => PluralCategory::Many,
+                        "other" => PluralCategory::Other,
+                        _ => {
+                            return Err(self.error(format!("Invalid plural selector `{}`", keyword)))
+                        }
+                    };
+                    Selector::Category(category)
+                }
+            };
+            let message = self.parse_case_message(true)?;
+            cases.push((selector, message));
+        }
+
+        if !cases
+            .iter()
+            .any(|(selector, _)| *selector == Selector::Category(PluralCategory::Other))
+        {
+            return Err(self.error("Missing the `other` case"));
+        }
+
+        Ok(Part::Plural {
+            name,
+            rule_type,
+            offset,
+            cases,
+        })
+    }
+
+    fn parse_select(&mut self, name: String, in_plural: bool) -> Result<Part, IcuError> {
+        let mut cases = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if matches!(self.peek(), Some('}') | None) {
+                break;
+            }
+            let keyword = self.parse_identifier()?;
+            let message = self.parse_case_message(in_plural)?;
+            cases.push((keyword, message));
+        }
+
+        if !cases.iter().any(|(keyword, _)| keyword == "other") {
+            return Err(self.error("Missing the `other` case"));
+        }
+
+        Ok(Part::Select { name, cases })
+    }
+
+    fn parse_number(&mut self) -> Result<f64, IcuError> {
+        let mut number = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() || c == '.' || (c == '-' && number.is_empty()) {
+                number.push(c);
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        number.parse().map_err(|_| self.error("Expected a number"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(locale: &str, input: &str, args: &[(&str, &str)]) -> String {
+        let patterns: Vec<_> = args.iter().map(|(k, _)| *k).collect();
+        let values: Vec<_> = args.iter().map(|(_, v)| v.to_string()).collect();
+        IcuMessage::parse(input)
+            .unwrap()
+            .format(locale, &patterns, &values)
+    }
+
+    #[test]
+    fn test_format_icu_translation() {
+        let input = "{count, plural, one {# file} other {# files}} in {dir}";
+        let format = |count: &str| {
+            _format_icu_translation(
+                "en",
+                input,
+                &["count", "dir"],
+                &[count.into(), "/tmp".into()],
+            )
+        };
+        assert_eq!(format("1"), "1 file in /tmp");
+        assert!(PARSED_TRANSLATIONS.read().unwrap()[input].is_some());
+        assert_eq!(format("3"), "3 files in /tmp");
+
+        let invalid = "{count, plural, one {# file}";
+        assert_eq!(_format_icu_translation("en", invalid, &[], &[]), invalid);
+        assert!(PARSED_TRANSLATIONS.read().unwrap()[invalid].is_none());
+        assert_eq!(_format_icu_translation("en", invalid, &[], &[]), invalid);
+    }
+
+    #[test]
+    fn test_format_simple() {
+        assert_eq!(format("en", "Hello world", &[]), "Hello world");
+        assert_eq!(
+            format("en", "Hello {name}!", &[("name", "Jason")]),
+            "Hello Jason!"
+        );
+        assert_eq!(format("en", "Hello {name}!", &[]), "Hello {name}!");
+        assert_eq!(
+            format("en", "Total: {n, number}", &[("n", "12")]),
+            "Total: 12"
+        );
+        assert_eq!(
+            format("en", "On {d, date, short}", &[("d", "2024-01-02")]),
+            "On 2024-01-02"
+        );
+        // The `%` of the default placeholders is plain text in ICU
+        assert_eq!(format("en", "Hello %{name}", &[("name", "x")]), "Hello %x");
+    }
+
+    #[test]
+    fn test_format_quoting() {
+        assert_eq!(format("en", "It''s {name}", &[("name", "me")]), "It's me");
+        assert_eq!(format("en", "It's", &[]), "It's");
+        assert_eq!(
+            format("en", "'{name}' is {name}", &[("name", "x")]),
+            "{name} is x"
+        );
+        assert_eq!(format("en", "'{'quoted''s'}'", &[]), "{quoted's}");
+        assert_eq!(
+            format("en", "{n, plural, other {'#' is #}}", &[("n", "3")]),
+            "# is 3"
+        );
+    }
+
+    #[test]
+    fn test_format_plural() {
+        let input = "{count, plural, =0 {No files} one {# file} other {# files}}";
+        assert_eq!(format("en", input, &[("count", "0")]), "No files");
+        assert_eq!(format("en", input, &[("count", "1")]), "1 file");
+        assert_eq!(format("en", input, &[("count", "2")]), "2 files");
+        assert_eq!(format("en", input, &[("count", "1.5")]), "1.5 files");
+        assert_eq!(format("en", input, &[]), "# files");
+
+        let input = "{count, plural, one {# файл} few {# файла} many {# файлов} other {# файла}}";
+        assert_eq!(format("ru", input, &[("count", "21")]), "21 файл");
+        assert_eq!(format("ru", input, &[("count", "3")]), "3 файла");
+        assert_eq!(format("ru", input, &[("count", "5")]), "5 файлов");
+
+        let input = "{guests, plural, offset:1 =0 {Nobody} =1 {{host}} one {{host} and # other} other {{host} and # others}}";
+        assert_eq!(
+            format("en", input, &[("guests", "0"), ("host", "Ann")]),
+            "Nobody"
+        );
+        assert_eq!(
+            format("en", input, &[("guests", "1"), ("host", "Ann")]),
+            "Ann"
+        );
+        assert_eq!(
+            format("en", input, &[("guests", "2"), ("host", "Ann")]),
+            "Ann and 1 other"
+        );
+        assert_eq!(
+            format("en", input, &[("guests", "5"), ("host", "Ann")]),
+            "Ann and 4 others"
+        );
+    }
+
+    #[test]
+    fn test_format_selectordinal() {
+        let input = "{n, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}";
+        assert_eq!(format("en", input, &[("n", "1")]), "1st");
+        assert_eq!(format("en", input, &[("n", "22")]), "22nd");
+        assert_eq!(format("en", input, &[("n", "13")]), "13th");
+    }
+
+    #[test]
+    fn test_format_select() {
+        let input = "{gender, select, female {She} male {He} other {They}} liked {count, plural, one {# post} other {# posts}}.";
+        assert_eq!(
+            format("en", input, &[("gender", "female"), ("count", "1")]),
+            "She liked 1 post."
+        );
+        assert_eq!(
+            format("en", input, &[("gender", "x"), ("count", "2")]),
+            "They liked 2 posts."
+        );
+
+        // Nested select in plural still replaces `#`
+        let input = "{n, plural, other {{g, select, other {# items}}}}";
+        assert_eq!(format("en", input, &[("n", "4")]), "4 items");
+    }
+
+    #[test]
+    fn test_arguments() {
+        let message = IcuMessage::parse("Hello, {name}!").unwrap();
+        assert_eq!(message.arguments(), vec!["name"]);
+
+        let message = IcuMessage::parse(
+            "{gender, select, female {{name} liked} other {They liked}} {count, plural, one {# post by {name}} other {# posts}}",
+        )
+        .unwrap();
+        assert_eq!(message.arguments(), vec!["gender", "name", "count"]);
+
+        let message = IcuMessage::parse("No '{arguments}'").unwrap();
+        assert_eq!(message.arguments(), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = IcuMessage::parse("Hello {name").unwrap_err();
+        assert_eq!(err.message, "Expected `,` or `}` after the argument name");
+        assert_eq!((err.line, err.column), (1, 12));
+
+        let err = IcuMessage::parse("{n, plural, one {# file}}").unwrap_err();
+        assert_eq!(err.message, "Missing the `other` case");
+
+        let err = IcuMessage::parse("{n, plural, single {x} other {y}}").unwrap_err();
+        assert_eq!(err.message, "Invalid plural selector `single`");
+
+        let err = IcuMessage::parse("Hello\n}").unwrap_err();
+        assert_eq!(err.message, "Unexpected `}`");
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = IcuMessage::parse("{}").unwrap_err();
+        assert_eq!(err.message, "Expected an argument name");
+
+        let err = IcuMessage::parse("{g, select, other {x}").unwrap_err();
+        assert_eq!(err.message, "Expected `}`");
+        assert_eq!(err.to_string(), "Expected `}` at 1:22");
+    }
+
+    #[test]
+    fn test_format_icu_message() {
+        assert_eq!(
+            format_icu_message("en", "Hi {name}", &["name"], &["Jason".into()]),
+            "Hi Jason"
+        );
+        assert_eq!(format_icu_message("en", "Hi {name", &[], &[]), "Hi {name");
+    }
+}
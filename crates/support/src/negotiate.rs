@@ -0,0 +1,298 @@
+use crate::canonicalize_locale;
+use std::fmt;
+
+/// The error of selecting a locale that is not available.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedLocale {
+    /// The requested locale.
+    pub locale: String,
+}
+
+impl fmt::Display for UnsupportedLocale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The locale `{}` is not available", self.locale)
+    }
+}
+
+impl std::error::Error for UnsupportedLocale {}
+
+/// Parse an `Accept-Language` header into the language ranges, in order of preference.
+///
+/// The ranges are sorted by the `q` weight (default `1`), the ranges with the same weight keep their order.
+/// The ranges with `q=0` (not acceptable), the `*` wildcard and the malformed items are dropped.
+///
+/// ```
+/// # use rust_i18n_support::parse_accept_language;
+/// assert_eq!(
+///     parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5"),
+///     vec!["fr-CH", "fr", "en", "de"]
+/// );
+/// ```
+pub fn parse_accept_language(header: &str) -> Vec<&str> {
+    let mut ranges = header
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';').map(str::trim);
+            let range = parts.next().filter(|range| is_language_range(range))?;
+            let mut weight = 1.0;
+            for param in parts {
+                let (name, value) = param.split_once('=')?;
+                if name.trim().eq_ignore_ascii_case("q") {
+                    weight = parse_weight(value.trim())?;
+                }
+            }
+            (weight > 0.0).then_some((range, weight))
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    ranges.into_iter().map(|(range, _)| range).collect()
+}
+
+/// Whether the range is a language range of RFC 4647, except the `*` wildcard.
+///
+/// The `_` separator of the POSIX locales is also accepted.
+fn is_language_range(range: &str) -> bool {
+    range.split(['-', '_']).all(|part| {
+        !part.is_empty() && part.len() <= 8 && part.chars().all(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+/// Parse the `q` weight, a number between `0` and `1` with up to three decimals.
+fn parse_weight(value: &str) -> Option<f32> {
+    let (int, frac) = value.split_once('.').unwrap_or((value, ""));
+    if !matches!(int, "0" | "1") || frac.len() > 3 || !frac.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let weight = value.parse::<f32>().ok()?;
+    (weight <= 1.0).then_some(weight)
+}
+
+/// Pick the best match of the requested locales among the available locales, with the RFC 4647 lookup.
+///
+/// Each requested locale (in order of preference) is truncated with `lookup_fallback` until it matches
+/// an available locale, for example `"zh-Hant-TW"` -> `"zh-Hant"` -> `"zh"`.
+/// The requested locales are canonicalized and matched case-insensitively, and the available locale is returned.
+///
+/// ```
+/// # use rust_i18n_support::negotiate_locale;
+/// fn lookup_fallback(locale: &str) -> Option<&str> {
+///     locale.rfind('-').map(|n| &locale[..n])
+/// }
+///
+/// let available = ["en", "zh", "zh-Hant"];
+/// assert_eq!(negotiate_locale(&["zh_Hant_TW", "en"], &available, lookup_fallback), Some("zh-Hant"));
+/// assert_eq!(negotiate_locale(&["fr", "EN-us"], &available, lookup_fallback), Some("en"));
+/// assert_eq!(negotiate_locale(&["fr"], &available, lookup_fallback), None);
+/// ```
+pub fn negotiate_locale<'a, S: AsRef<str>>(
+    requested: &[S],
+    available: &[&'a str],
+    lookup_fallback: impl Fn(&str) -> Option<&str>,
+) -> Option<&'a str> {
+    requested.iter().find_map(|locale| {
+        let locale = canonicalize_locale(locale.as_ref());
+        let mut range = Some(locale.as_ref()).filter(|range| !range.is_empty());
+        while let Some(current) = range {
+            let found = available.iter().find(|available| {
+                available.len() == current.len()
+                    && available
+                        .chars()
+                        .zip(current.chars())
+                        .all(|(a, b)| a.eq_ignore_ascii_case(&b) || (a == '_' && b == '-'))
+            });
+            if let Some(found) = found {
+                return Some(*found);
+            }
+            range = lookup_fallback(current);
+        }
+        None
+    })
+}
+
+/// Convert a POSIX locale, such as `zh_CN.UTF-8` or `sr_RS@latin`, to a BCP 47 language tag, such as `zh-CN` or `sr-Latn-RS`.
+///
+/// The codeset is dropped, and the `@latin` and `@cyrillic` modifiers are converted to the script subtags, other modifiers are dropped.
+/// Returns `None` for the `C` and `POSIX` locales, and the malformed values.
+///
+/// ```
+/// # use rust_i18n_support::posix_to_bcp47;
+/// assert_eq!(posix_to_bcp47("zh_CN.UTF-8").as_deref(), Some("zh-CN"));
+/// assert_eq!(posix_to_bcp47("sr_RS@latin").as_deref(), Some("sr-Latn-RS"));
+/// assert_eq!(posix_to_bcp47("C.UTF-8"), None);
+/// ```
+pub fn posix_to_bcp47(locale: &str) -> Option<String> {
+    let (locale, modifier) = locale.trim().split_once('@').unwrap_or((locale.trim(), ""));
+    let locale = locale.split('.').next().unwrap_or_default();
+    if matches!(locale, "" | "C" | "POSIX") || !is_language_range(locale) {
+        return None;
+    }
+
+    let mut subtags = locale.split(['_', '-']);
+    let mut result = subtags.next().unwrap_or_default().to_string();
+    match modifier {
+        "latin" => result.push_str("-Latn"),
+        "cyrillic" => result.push_str("-Cyrl"),
+        _ => {}
+    }
+    for subtag in subtags {
+        result.push('-');
+        result.push_str(subtag);
+    }
+    Some(result)
+}
+
+/// Get the locales of the user from the environment variables, as BCP 47 language tags in order of preference.
+///
+/// The locale of the messages is the first non-empty one of `LC_ALL`, `LC_MESSAGES` and `LANG`,
+/// and it follows the colon-separated list of `LANGUAGE`, such as `de_AT:de:en`.
+/// Like GNU gettext, `LANGUAGE` is ignored if the locale is `C` or `POSIX`.
+pub fn env_locales() -> Vec<String> {
+    env_locales_with(|name| std::env::var(name).ok())
+}
+
+fn env_locales_with(var: impl Fn(&str) -> Option<String>) -> Vec<String> {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .find_map(|name| var(name).filter(|value| !value.trim().is_empty()));
+    let locale = match locale {
+        Some(locale) => match posix_to_bcp47(&locale) {
+            Some(locale) => Some(locale),
+            None => return vec![],
+        },
+        None => None,
+    };
+
+    let mut result: Vec<String> = vec![];
+    let language = var("LANGUAGE").unwrap_or_default();
+    for locale in language.split(':').filter_map(posix_to_bcp47).chain(locale) {
+        if !result.contains(&locale) {
+            result.push(locale);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup_fallback(locale: &str) -> Option<&str> {
+        locale
+            .rfind('-')
+            .map(|n| locale[..n].trim_end_matches("-x"))
+    }
+
+    #[test]
+    fn test_parse_accept_language() {
+        assert_eq!(parse_accept_language(""), Vec::<&str>::new());
+        assert_eq!(parse_accept_language("en"), vec!["en"]);
+        assert_eq!(
+            parse_accept_language("da, en-GB;q=0.8, en;q=0.7"),
+            vec!["da", "en-GB", "en"]
+        );
+        // Sorted by weight, stable for the same weight
+        assert_eq!(
+            parse_accept_language("en;q=0.5,fr,de;q=0.9,ja;Q=0.9"),
+            vec!["fr", "de", "ja", "en"]
+        );
+        assert_eq!(
+            parse_accept_language(" zh-Hant-TW ; q=1.0 ,zh_CN;q=0.999"),
+            vec!["zh-Hant-TW", "zh_CN"]
+        );
+        // Not acceptable, wildcard and malformed
+        assert_eq!(
+            parse_accept_language("en;q=0, fr;q=0.000, *"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            parse_accept_language("en;q=2, fr;q=abc, de;q=0.1234, ja;q, ko-, , pt;level=1"),
+            vec!["pt"]
+        );
+    }
+
+    #[test]
+    fn test_negotiate_locale() {
+        let available = ["de", "en", "he", "zh", "zh-Hant", "pt_BR"];
+        let negotiate =
+            |requested: &[&str]| negotiate_locale(requested, &available, lookup_fallback);
+
+        assert_eq!(negotiate(&["en"]), Some("en"));
+        assert_eq!(negotiate(&["en-US"]), Some("en"));
+        assert_eq!(negotiate(&["ZH-hant-tw"]), Some("zh-Hant"));
+        assert_eq!(negotiate(&["zh-Hans-CN"]), Some("zh"));
+        assert_eq!(negotiate(&["zh-Hant-CN-x-private1"]), Some("zh-Hant"));
+        assert_eq!(negotiate(&["pt-BR"]), Some("pt_BR"));
+        assert_eq!(negotiate(&["iw-IL"]), Some("he"));
+        // In order of preference, not by the length of the match
+        assert_eq!(negotiate(&["fr", "de-AT", "zh-Hant"]), Some("de"));
+        assert_eq!(negotiate(&["fr", "ja"]), None);
+        assert_eq!(negotiate(&["", " "]), None);
+        assert_eq!(negotiate(&[]), None);
+    }
+
+    #[test]
+    fn test_posix_to_bcp47() {
+        let bcp47 = |locale| posix_to_bcp47(locale);
+        assert_eq!(bcp47("en").as_deref(), Some("en"));
+        assert_eq!(bcp47("en_US").as_deref(), Some("en-US"));
+        assert_eq!(bcp47("zh_CN.UTF-8").as_deref(), Some("zh-CN"));
+        assert_eq!(bcp47("de_DE.ISO-8859-1@euro").as_deref(), Some("de-DE"));
+        assert_eq!(bcp47("sr_RS@latin").as_deref(), Some("sr-Latn-RS"));
+        assert_eq!(bcp47("uz_UZ.UTF-8@cyrillic").as_deref(), Some("uz-Cyrl-UZ"));
+        assert_eq!(bcp47("pt-BR").as_deref(), Some("pt-BR"));
+        assert_eq!(bcp47("C"), None);
+        assert_eq!(bcp47("C.UTF-8"), None);
+        assert_eq!(bcp47("POSIX"), None);
+        assert_eq!(bcp47(""), None);
+        assert_eq!(bcp47("/usr/lib/locale/en"), None);
+    }
+
+    #[test]
+    fn test_env_locales() {
+        let env_locales = |vars: &[(&str, &str)]| {
+            env_locales_with(|name| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            })
+        };
+
+        assert_eq!(env_locales(&[]), Vec::<String>::new());
+        assert_eq!(env_locales(&[("LANG", "zh_CN.UTF-8")]), vec!["zh-CN"]);
+        assert_eq!(
+            env_locales(&[("LANG", "en_US.UTF-8"), ("LC_MESSAGES", "de_DE.UTF-8")]),
+            vec!["de-DE"]
+        );
+        assert_eq!(
+            env_locales(&[
+                ("LANG", "en_US"),
+                ("LC_MESSAGES", "de_DE"),
+                ("LC_ALL", "fr_FR")
+            ]),
+            vec!["fr-FR"]
+        );
+        // Empty values are ignored
+        assert_eq!(
+            env_locales(&[("LANG", "ja_JP.UTF-8"), ("LC_ALL", "")]),
+            vec!["ja-JP"]
+        );
+        // LANGUAGE list first
+        assert_eq!(
+            env_locales(&[("LANG", "en_US.UTF-8"), ("LANGUAGE", "de_AT:de::en_US")]),
+            vec!["de-AT", "de", "en-US"]
+        );
+        assert_eq!(
+            env_locales(&[("LANGUAGE", "pt_BR:pt")]),
+            vec!["pt-BR", "pt"]
+        );
+        // LANGUAGE is ignored with the C locale
+        assert_eq!(
+            env_locales(&[("LANG", "C.UTF-8"), ("LANGUAGE", "de")]),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            env_locales(&[("LC_ALL", "POSIX"), ("LANG", "de_DE")]),
+            Vec::<String>::new()
+        );
+    }
+}
@@ -0,0 +1,460 @@
+use crate::locale::locale_entry;
+
+/// The style of a locale-aware number, selected by `%{name:number}` in a translation or `name = value : {number}` in `t!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberStyle {
+    /// `number`, the number with the grouping and decimal separators, such as `1,234.5`.
+    Number,
+    /// `percent`, the number multiplied by 100 with the percent sign, such as `12.5%` of `0.125`.
+    Percent,
+}
+
+impl NumberStyle {
+    /// Parse the style name, returns `None` if it's not a number style.
+    pub fn parse(style: &str) -> Option<Self> {
+        match style {
+            "number" => Some(Self::Number),
+            "percent" => Some(Self::Percent),
+            _ => None,
+        }
+    }
+}
+
+/// The symbols to format the numbers in a locale.
+struct NumberSymbols {
+    group: &'static str,
+    decimal: &'static str,
+    minus: &'static str,
+    /// The percent pattern, `{}` is replaced with the number.
+    percent: &'static str,
+    /// The currency pattern, `{}` is replaced with the number and `¤` with the currency symbol.
+    currency: &'static str,
+    /// The native digits `0` to `9`, or `None` for the ASCII digits.
+    digits: Option<[char; 10]>,
+    /// The minimum integer digits to use the grouping, such as `5` for `1234` but `12.345` in Spanish.
+    min_grouping: usize,
+    /// Group the integer digits by 3 then 2, such as `12,34,567` in India.
+    indian_grouping: bool,
+}
+
+const LATIN: NumberSymbols = NumberSymbols {
+    group: ",",
+    decimal: ".",
+    minus: "-",
+    percent: "{}%",
+    currency: "¤{}",
+    digits: None,
+    min_grouping: 4,
+    indian_grouping: false,
+};
+
+const ARABIC_DIGITS: [char; 10] = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+const PERSIAN_DIGITS: [char; 10] = ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'];
+
+/// The number symbols of the locales, from CLDR, the other locales use [`LATIN`].
+const LOCALE_SYMBOLS: &[(&str, NumberSymbols)] = &[
+    (
+        "ar",
+        NumberSymbols {
+            group: "\u{066C}",
+            decimal: "\u{066B}",
+            minus: "\u{061C}-",
+            percent: "{}\u{066A}\u{061C}",
+            currency: "{}\u{A0}¤",
+            digits: Some(ARABIC_DIGITS),
+            ..LATIN
+        },
+    ),
+    ("ar-DZ", comma_decimal(".", "{}%")),
+    ("ar-MA", comma_decimal(".", "{}%")),
+    ("ar-TN", comma_decimal(".", "{}%")),
+    ("bg", comma_decimal("\u{A0}", "{}%")),
+    ("cs", comma_decimal("\u{A0}", "{}\u{A0}%")),
+    ("da", comma_decimal(".", "{}\u{A0}%")),
+    ("de", comma_decimal(".", "{}\u{A0}%")),
+    (
+        "de-AT",
+        NumberSymbols {
+            currency: "¤\u{A0}{}",
+            ..comma_decimal("\u{A0}", "{}\u{A0}%")
+        },
+    ),
+    (
+        "de-CH",
+        NumberSymbols {
+            group: "’",
+            currency: "¤\u{A0}{}",
+            ..LATIN
+        },
+    ),
+    ("el", comma_decimal(".", "{}%")),
+    (
+        "en-IN",
+        NumberSymbols {
+            indian_grouping: true,
+            ..LATIN
+        },
+    ),
+    (
+        "es",
+        NumberSymbols {
+            min_grouping: 5,
+            ..comma_decimal(".", "{}\u{A0}%")
+        },
+    ),
+    (
+        "fa",
+        NumberSymbols {
+            group: "\u{066C}",
+            decimal: "\u{066B}",
+            minus: "\u{200E}\u{2212}",
+            percent: "{}\u{066A}",
+            digits: Some(PERSIAN_DIGITS),
+            ..LATIN
+        },
+    ),
+    ("fi", comma_decimal("\u{A0}", "{}\u{A0}%")),
+    ("fr", comma_decimal("\u{202F}", "{}\u{202F}%")),
+    ("fr-CH", comma_decimal("\u{202F}", "{}%")),
+    (
+        "hi",
+        NumberSymbols {
+            indian_grouping: true,
+            ..LATIN
+        },
+    ),
+    ("hr", comma_decimal(".", "{}\u{A0}%")),
+    ("hu", comma_decimal("\u{A0}", "{}%")),
+    (
+        "id",
+        NumberSymbols {
+            currency: "¤{}",
+            ..comma_decimal(".", "{}%")
+        },
+    ),
+    ("it", comma_decimal(".", "{}%")),
+    ("nb", comma_decimal("\u{A0}", "{}\u{A0}%")),
+    (
+        "nl",
+        NumberSymbols {
+            currency: "¤\u{A0}{}",
+            ..comma_decimal(".", "{}%")
+        },
+    ),
+    (
+        "pl",
+        NumberSymbols {
+            min_grouping: 5,
+            ..comma_decimal("\u{A0}", "{}%")
+        },
+    ),
+    (
+        "pt",
+        NumberSymbols {
+            currency: "¤\u{A0}{}",
+            ..comma_decimal(".", "{}%")
+        },
+    ),
+    (
+        "pt-PT",
+        NumberSymbols {
+            min_grouping: 5,
+            ..comma_decimal("\u{A0}", "{}%")
+        },
+    ),
+    ("ro", comma_decimal(".", "{}\u{A0}%")),
+    ("ru", comma_decimal("\u{A0}", "{}\u{A0}%")),
+    ("sk", comma_decimal("\u{A0}", "{}\u{A0}%")),
+    ("sl", comma_decimal(".", "{}\u{A0}%")),
+    ("sr", comma_decimal(".", "{}%")),
+    ("sv", comma_decimal("\u{A0}", "{}\u{A0}%")),
+    (
+        "tr",
+        NumberSymbols {
+            currency: "¤{}",
+            ..comma_decimal(".", "%{}")
+        },
+    ),
+    ("uk", comma_decimal("\u{A0}", "{}%")),
+    ("vi", comma_decimal(".", "{}%")),
+];
+
+/// The symbols of the locales with the `,` decimal separator, and the currency symbol after the number.
+const fn comma_decimal(group: &'static str, percent: &'static str) -> NumberSymbols {
+    NumberSymbols {
+        group,
+        decimal: ",",
+        percent,
+        currency: "{}\u{A0}¤",
+        ..LATIN
+    }
+}
+
+/// Find the symbols of the locale, or of its language, such as `de-AT` -> `de`.
+fn number_symbols(locale: &str) -> &'static NumberSymbols {
+    locale_entry(LOCALE_SYMBOLS, locale).unwrap_or(&LATIN)
+}
+
+/// Format a number in the locale, with its grouping and decimal separators, and its native digits such as in `ar` and `fa`.
+///
+/// The number is the decimal text of any number type, such as `1234567.5` or `-42`, so the precision is kept as-is.
+/// The text is returned as-is if it's not a decimal number, such as `NaN` or `inf`.
+///
+/// ```
+/// # use rust_i18n_support::{format_number, NumberStyle};
+/// assert_eq!(format_number("en", "1234567.5", NumberStyle::Number), "1,234,567.5");
+/// assert_eq!(format_number("de", "1234567.5", NumberStyle::Number), "1.234.567,5");
+/// assert_eq!(format_number("ar", "1234.5", NumberStyle::Number), "١٬٢٣٤٫٥");
+/// assert_eq!(format_number("en", "0.125", NumberStyle::Percent), "12.5%");
+/// ```
+pub fn format_number(locale: &str, number: &str, style: NumberStyle) -> String {
+    format_number_with_precision(locale, number, style, None)
+}
+
+/// Format a number in the locale like [`format_number`], rounded to the fraction digits of the precision if any,
+/// half to even, such as `1,234.50` of `1234.5` with `Some(2)`. The percent is rounded after it's multiplied by 100.
+///
+/// ```
+/// # use rust_i18n_support::{format_number_with_precision, NumberStyle};
+/// assert_eq!(format_number_with_precision("de", "1234.5", NumberStyle::Number, Some(2)), "1.234,50");
+/// assert_eq!(format_number_with_precision("en", "0.12345", NumberStyle::Percent, Some(1)), "12.3%");
+/// assert_eq!(format_number_with_precision("en", "2.5", NumberStyle::Number, Some(0)), "2");
+/// ```
+pub fn format_number_with_precision(
+    locale: &str,
+    number: &str,
+    style: NumberStyle,
+    precision: Option<usize>,
+) -> String {
+    let Some((negative, integer, fraction)) = parse_decimal(number) else {
+        return number.to_string();
+    };
+    let (integer, fraction) = match style {
+        NumberStyle::Number => (integer.to_string(), fraction.to_string()),
+        NumberStyle::Percent => shift_decimal(integer, fraction, 2),
+    };
+    let (integer, fraction) = match precision {
+        Some(digits) => round_decimal(&integer, &fraction, digits),
+        None => (integer, fraction),
+    };
+
+    let symbols = number_symbols(locale);
+    let mut output = String::with_capacity(number.len() * 2);
+    if negative {
+        output.push_str(symbols.minus);
+    }
+    let len = integer.len();
+    for (i, digit) in integer.chars().enumerate() {
+        let remaining = len - i;
+        if i > 0 && len >= symbols.min_grouping && is_group_boundary(remaining, symbols) {
+            output.push_str(symbols.group);
+        }
+        output.push(native_digit(digit, symbols));
+    }
+    if !fraction.is_empty() {
+        output.push_str(symbols.decimal);
+        output.extend(fraction.chars().map(|digit| native_digit(digit, symbols)));
+    }
+
+    match style {
+        NumberStyle::Number => output,
+        NumberStyle::Percent => symbols.percent.replace("{}", &output),
+    }
+}
+
+/// The minus sign of the locale.
+pub(crate) fn minus_sign(locale: &str) -> &'static str {
+    number_symbols(locale).minus
+}
+
+/// The percent pattern of the locale, `{}` is replaced with the number.
+pub(crate) fn percent_pattern(locale: &str) -> &'static str {
+    number_symbols(locale).percent
+}
+
+/// The currency pattern of the locale, `{}` is replaced with the number and `¤` with the currency symbol.
+pub(crate) fn currency_pattern(locale: &str) -> &'static str {
+    number_symbols(locale).currency
+}
+
+/// Whether a group separator is before the digit with `remaining` digits to the end of the integer.
+fn is_group_boundary(remaining: usize, symbols: &NumberSymbols) -> bool {
+    if symbols.indian_grouping && remaining > 3 {
+        (remaining - 3) % 2 == 0
+    } else {
+        remaining % 3 == 0
+    }
+}
+
+fn native_digit(digit: char, symbols: &NumberSymbols) -> char {
+    match (symbols.digits, digit.to_digit(10)) {
+        (Some(digits), Some(n)) => digits[n as usize],
+        _ => digit,
+    }
+}
+
+/// Parse a decimal number into the sign, the integer digits and the fraction digits.
+pub(crate) fn parse_decimal(number: &str) -> Option<(bool, &str, &str)> {
+    let (negative, unsigned) = match number.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, number.strip_prefix('+').unwrap_or(number)),
+    };
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+        return None;
+    }
+    Some((negative, integer, fraction))
+}
+
+/// Multiply the decimal number by `10^places`, without the floating point errors.
+fn shift_decimal(integer: &str, fraction: &str, places: usize) -> (String, String) {
+    let mut digits = format!("{}{}", integer, fraction);
+    let point = integer.len() + places;
+    while digits.len() < point {
+        digits.push('0');
+    }
+    let (integer, fraction) = digits.split_at(point);
+    let integer = integer.trim_start_matches('0');
+    let integer = if integer.is_empty() { "0" } else { integer };
+    (integer.to_string(), fraction.to_string())
+}
+
+/// Round the decimal number to `digits` fraction digits, half to even, and pad the fraction with zeros.
+pub(crate) fn round_decimal(integer: &str, fraction: &str, digits: usize) -> (String, String) {
+    if fraction.len() <= digits {
+        return (integer.to_string(), format!("{:0<digits$}", fraction));
+    }
+
+    let (kept, dropped) = fraction.split_at(digits);
+    let mut number = format!("{}{}", integer, kept).into_bytes();
+    let last_is_odd = number.last().is_some_and(|b| (b - b'0') % 2 == 1);
+    let round_up = match dropped.as_bytes()[0] {
+        b'6'..=b'9' => true,
+        b'5' => dropped[1..].bytes().any(|b| b != b'0') || last_is_odd,
+        _ => false,
+    };
+    if round_up {
+        let mut carry = true;
+        for digit in number.iter_mut().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            number.insert(0, b'1');
+        }
+    }
+
+    let number = String::from_utf8(number).unwrap_or_default();
+    let (integer, fraction) = number.split_at(number.len() - digits);
+    (integer.to_string(), fraction.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number() {
+        let format = |locale, number| format_number(locale, number, NumberStyle::Number);
+        assert_eq!(format("en", "0"), "0");
+        assert_eq!(format("en", "123"), "123");
+        assert_eq!(format("en", "1234"), "1,234");
+        assert_eq!(format("en", "-1234567.891"), "-1,234,567.891");
+        assert_eq!(format("en", "+1000"), "1,000");
+        assert_eq!(format("zh-CN", "1234567"), "1,234,567");
+        assert_eq!(format("de", "1234567.5"), "1.234.567,5");
+        assert_eq!(format("de-DE", "1234567.5"), "1.234.567,5");
+        assert_eq!(format("de_at", "1234567.5"), "1\u{A0}234\u{A0}567,5");
+        assert_eq!(format("de-CH", "1234567.5"), "1’234’567.5");
+        assert_eq!(format("fr", "1234567.5"), "1\u{202F}234\u{202F}567,5");
+        assert_eq!(format("ru", "-1234.5"), "-1\u{A0}234,5");
+        // The minimum grouping digits
+        assert_eq!(format("es", "1234"), "1234");
+        assert_eq!(format("es", "12345"), "12.345");
+        assert_eq!(format("pl", "1234.5"), "1234,5");
+        // The Indian grouping
+        assert_eq!(format("hi", "1234567.5"), "12,34,567.5");
+        assert_eq!(format("en-IN", "123456789"), "12,34,56,789");
+        assert_eq!(format("en-IN", "1234"), "1,234");
+        // The native digits
+        assert_eq!(format("ar", "1234567.5"), "١٬٢٣٤٬٥٦٧٫٥");
+        assert_eq!(format("ar-EG", "-12"), "\u{061C}-١٢");
+        assert_eq!(format("ar-MA", "1234.5"), "1.234,5");
+        assert_eq!(format("fa", "1234.5"), "۱٬۲۳۴٫۵");
+        // Not a decimal number
+        assert_eq!(format("en", "NaN"), "NaN");
+        assert_eq!(format("en", "inf"), "inf");
+        assert_eq!(format("en", "abc"), "abc");
+        assert_eq!(format("en", ".5"), ".5");
+        assert_eq!(format("en", ""), "");
+    }
+
+    #[test]
+    fn test_format_percent() {
+        let format = |locale, number| format_number(locale, number, NumberStyle::Percent);
+        assert_eq!(format("en", "0.125"), "12.5%");
+        assert_eq!(format("en", "0.07"), "7%");
+        assert_eq!(format("en", "1"), "100%");
+        assert_eq!(format("en", "12.5"), "1,250%");
+        assert_eq!(format("en", "-0.5"), "-50%");
+        assert_eq!(format("de", "0.125"), "12,5\u{A0}%");
+        assert_eq!(format("fr", "0.5"), "50\u{202F}%");
+        assert_eq!(format("tr", "0.5"), "%50");
+        assert_eq!(format("ar", "0.5"), "٥٠\u{066A}\u{061C}");
+    }
+
+    #[test]
+    fn test_format_number_with_precision() {
+        let format = |locale, number, style, digits| {
+            format_number_with_precision(locale, number, style, Some(digits))
+        };
+        assert_eq!(format("en", "1234.5", NumberStyle::Number, 2), "1,234.50");
+        assert_eq!(format("en", "1234.567", NumberStyle::Number, 2), "1,234.57");
+        assert_eq!(format("en", "999.996", NumberStyle::Number, 2), "1,000.00");
+        assert_eq!(format("de", "-1234.5", NumberStyle::Number, 0), "-1.234");
+        assert_eq!(format("ar", "1.5", NumberStyle::Number, 2), "١٫٥٠");
+        assert_eq!(format("en", "0.125", NumberStyle::Percent, 0), "12%");
+        assert_eq!(format("en", "0.07", NumberStyle::Percent, 1), "7.0%");
+        // Half to even
+        assert_eq!(
+            format("de", "0.12345", NumberStyle::Percent, 2),
+            "12,34\u{A0}%"
+        );
+        assert_eq!(
+            format("de", "0.12355", NumberStyle::Percent, 2),
+            "12,36\u{A0}%"
+        );
+        assert_eq!(format("en", "NaN", NumberStyle::Number, 2), "NaN");
+    }
+
+    #[test]
+    fn test_round_decimal() {
+        let round = |integer, fraction, digits| round_decimal(integer, fraction, digits);
+        assert_eq!(round("12", "", 2), ("12".into(), "00".into()));
+        assert_eq!(round("12", "5", 2), ("12".into(), "50".into()));
+        assert_eq!(round("12", "345", 2), ("12".into(), "34".into()));
+        assert_eq!(round("12", "346", 2), ("12".into(), "35".into()));
+        assert_eq!(round("12", "3451", 2), ("12".into(), "35".into()));
+        // Half to even
+        assert_eq!(round("12", "125", 2), ("12".into(), "12".into()));
+        assert_eq!(round("12", "135", 2), ("12".into(), "14".into()));
+        assert_eq!(round("2", "5", 0), ("2".into(), "".into()));
+        assert_eq!(round("3", "5", 0), ("4".into(), "".into()));
+        // The carry
+        assert_eq!(round("99", "996", 2), ("100".into(), "00".into()));
+        assert_eq!(round("0", "4", 0), ("0".into(), "".into()));
+    }
+
+    #[test]
+    fn test_number_style() {
+        assert_eq!(NumberStyle::parse("number"), Some(NumberStyle::Number));
+        assert_eq!(NumberStyle::parse("percent"), Some(NumberStyle::Percent));
+        assert_eq!(NumberStyle::parse("08"), None);
+    }
+}
@@ -0,0 +1,216 @@
+/// A segment of a translation, split by the `%{name}` placeholders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment<'a> {
+    /// Text to output as-is, with the escapes resolved.
+    Text(&'a str),
+    /// The name of a `%{name}` placeholder.
+    Placeholder(&'a str),
+}
+
+/// Split a translation into text and `%{name}` placeholders.
+///
+/// - `%%{name}` is an escaped `%{name}`, the output is the literal `%{name}`.
+///   In a run of `%` before `{`, each `%%` is one literal `%`, and an odd `%` starts a placeholder.
+/// - A placeholder name can not contain `{`, `}` or `%`, so in `%{%{name}}` only `%{name}` is a placeholder.
+/// - The `%` not followed by `{` is always literal, `100%` and `100%%` are kept as-is.
+///
+/// ```
+/// # use rust_i18n_support::{parse_segments, Segment};
+/// let segments = parse_segments("Use %%{name} for %{what}").collect::<Vec<_>>();
+/// assert_eq!(
+///     segments,
+///     vec![
+///         Segment::Text("Use %"),
+///         Segment::Text("{name} for "),
+///         Segment::Placeholder("what"),
+///     ]
+/// );
+/// ```
+pub fn parse_segments(input: &str) -> Segments<'_> {
+    Segments {
+        input,
+        pos: 0,
+        pending: None,
+    }
+}
+
+/// The iterator of [`parse_segments`].
+#[derive(Debug, Clone)]
+pub struct Segments<'a> {
+    input: &'a str,
+    pos: usize,
+    pending: Option<Segment<'a>>,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pending) = self.pending.take() {
+            return Some(pending);
+        }
+
+        let bytes = self.input.as_bytes();
+        let start = self.pos;
+        let mut i = start;
+        while i < bytes.len() {
+            if bytes[i] != b'%' {
+                i += 1;
+                continue;
+            }
+
+            let run_start = i;
+            while i < bytes.len() && bytes[i] == b'%' {
+                i += 1;
+            }
+            if i == bytes.len() || bytes[i] != b'{' {
+                continue;
+            }
+
+            // A run of `%` before `{`, the pairs are escaped `%`
+            let run_len = i - run_start;
+            let text_end = run_start + run_len / 2;
+            if run_len % 2 == 0 {
+                // Continue the text from the `{`, after the dropped `%`
+                self.pos = i;
+                return self.text(start, text_end);
+            }
+
+            let name_start = i + 1;
+            let name_len = bytes[name_start..]
+                .iter()
+                .position(|b| matches!(b, b'}' | b'{' | b'%'));
+            match name_len {
+                Some(len) if bytes[name_start + len] == b'}' => {
+                    let name = &self.input[name_start..name_start + len];
+                    self.pos = name_start + len + 1;
+                    if start == text_end {
+                        return Some(Segment::Placeholder(name));
+                    }
+                    self.pending = Some(Segment::Placeholder(name));
+                    return self.text(start, text_end);
+                }
+                _ => {
+                    // Not a placeholder, the last `%` and `{` are literal
+                    self.pos = i - 1;
+                    if start != text_end {
+                        return self.text(start, text_end);
+                    }
+                    i = name_start;
+                    continue;
+                }
+            }
+        }
+
+        self.pos = bytes.len();
+        if start == bytes.len() {
+            None
+        } else {
+            Some(Segment::Text(&self.input[start..]))
+        }
+    }
+}
+
+impl<'a> Segments<'a> {
+    fn text(&self, start: usize, end: usize) -> Option<Segment<'a>> {
+        Some(Segment::Text(&self.input[start..end]))
+    }
+}
+
+/// Extract the names of the `%{name}` placeholders in a translation, in order of appearance.
+///
+/// The escaped `%%{name}` is not a placeholder, and the format of `%{name:number}` is not a part of the name.
+///
+/// ```
+/// # use rust_i18n_support::extract_placeholders;
+/// assert_eq!(extract_placeholders("Hello, %{name}! %{msg}"), vec!["name", "msg"]);
+/// assert_eq!(extract_placeholders("Total: %{amount:number}"), vec!["amount"]);
+/// assert_eq!(extract_placeholders("100%"), Vec::<&str>::new());
+/// assert_eq!(extract_placeholders("Use %%{name}"), Vec::<&str>::new());
+/// ```
+pub fn extract_placeholders(input: &str) -> Vec<&str> {
+    let mut result = vec![];
+    for segment in parse_segments(input) {
+        if let Segment::Placeholder(name) = segment {
+            let name = name.split_once(':').map_or(name, |(name, _)| name);
+            if !result.contains(&name) {
+                result.push(name);
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Render the segments with the placeholders as `<name>`.
+    fn render(input: &str) -> String {
+        parse_segments(input)
+            .map(|segment| match segment {
+                Segment::Text(text) => text.to_string(),
+                Segment::Placeholder(name) => format!("<{}>", name),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_segments() {
+        assert_eq!(parse_segments("").count(), 0);
+        assert_eq!(render("Hello"), "Hello");
+        assert_eq!(render("Hello, %{name}!"), "Hello, <name>!");
+        assert_eq!(render("%{name}"), "<name>");
+        assert_eq!(render("%{}"), "<>");
+        assert_eq!(render("100% {off} 100%% %"), "100% {off} 100%% %");
+        assert_eq!(render("你好，%{名字}！"), "你好，<名字>！");
+    }
+
+    #[test]
+    fn test_parse_segments_with_escapes() {
+        assert_eq!(render("%%{name}"), "%{name}");
+        assert_eq!(render("Use %%{name} for %{name}"), "Use %{name} for <name>");
+        assert_eq!(render("%%%{name}"), "%<name>");
+        assert_eq!(render("%%%%{name}"), "%%{name}");
+        assert_eq!(render("%%{"), "%{");
+        assert_eq!(render("50%%{off}%%"), "50%{off}%%");
+    }
+
+    #[test]
+    fn test_parse_segments_with_braces() {
+        // Adjacent
+        assert_eq!(render("%{a}%{b}"), "<a><b>");
+        assert_eq!(render("%{a}%%{b}%{c}"), "<a>%{b}<c>");
+        assert_eq!(render("{%{a}}"), "{<a>}");
+        assert_eq!(render("%{a}}}"), "<a>}}");
+        assert_eq!(render("{{%{a}"), "{{<a>");
+        // Nested
+        assert_eq!(render("%{%{name}}"), "%{<name>}");
+        assert_eq!(render("%{a{b}}"), "%{a{b}}");
+        assert_eq!(render("%{a%{b}}"), "%{a<b>}");
+        assert_eq!(render("%%%{%{b}}"), "%%{<b>}");
+        // Unclosed
+        assert_eq!(render("%{name"), "%{name");
+        assert_eq!(render("Hello %{"), "Hello %{");
+    }
+
+    #[test]
+    fn test_extract_placeholders() {
+        assert_eq!(extract_placeholders(""), Vec::<&str>::new());
+        assert_eq!(extract_placeholders("Hello"), Vec::<&str>::new());
+        assert_eq!(extract_placeholders("Hello, %{name}"), vec!["name"]);
+        assert_eq!(
+            extract_placeholders("%{a}%{b} and %{a} again"),
+            vec!["a", "b"]
+        );
+        assert_eq!(extract_placeholders("50% {off} %{"), Vec::<&str>::new());
+        assert_eq!(extract_placeholders("%%{name}"), Vec::<&str>::new());
+        assert_eq!(extract_placeholders("%%%{name}"), vec!["name"]);
+        assert_eq!(extract_placeholders("%{%{name}}"), vec!["name"]);
+        assert_eq!(extract_placeholders("你好，%{名字}"), vec!["名字"]);
+        assert_eq!(
+            extract_placeholders("%{amount:number} of %{amount} (%{rate:percent})"),
+            vec!["amount", "rate"]
+        );
+    }
+}
@@ -0,0 +1,755 @@
+use std::fmt;
+
+/// The CLDR plural categories.
+///
+/// https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// All categories, in the CLDR order.
+    pub const ALL: [Self; 6] = [
+        Self::Zero,
+        Self::One,
+        Self::Two,
+        Self::Few,
+        Self::Many,
+        Self::Other,
+    ];
+
+    /// Returns the name of the category, which is also the sub-key used in the locale files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The type of the CLDR plural rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralRuleType {
+    /// Cardinal numbers, e.g. "1 file", "2 files".
+    Cardinal,
+    /// Ordinal numbers, e.g. "1st", "2nd", "3rd".
+    Ordinal,
+}
+
+impl PluralRuleType {
+    /// Select the plural category of a number for the given locale.
+    pub fn category(&self, locale: &str, operands: &PluralOperands) -> PluralCategory {
+        match self {
+            Self::Cardinal => plural_category(locale, operands),
+            Self::Ordinal => ordinal_category(locale, operands),
+        }
+    }
+
+    /// Returns the sub-key prefix of the plural forms in the locale files.
+    ///
+    /// Cardinal forms are put directly under the key (`inbox.messages.one`),
+    /// ordinal forms are put under `ordinal` (`rank.ordinal.one`).
+    pub fn key_prefix(&self) -> &'static str {
+        match self {
+            Self::Cardinal => "",
+            Self::Ordinal => "ordinal.",
+        }
+    }
+}
+
+/// The operands of a number used by the CLDR plural rules.
+///
+/// - `n` - the absolute value of the number.
+/// - `i` - the integer digits of `n`.
+/// - `v` - the number of visible fraction digits in `n`, with trailing zeros.
+/// - `w` - the number of visible fraction digits in `n`, without trailing zeros.
+/// - `f` - the visible fraction digits in `n`, with trailing zeros.
+/// - `t` - the visible fraction digits in `n`, without trailing zeros.
+///
+/// https://unicode.org/reports/tr35/tr35-numbers.html#Operands
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PluralOperands {
+    pub n: f64,
+    pub i: u64,
+    pub v: usize,
+    pub w: usize,
+    pub f: u64,
+    pub t: u64,
+}
+
+/// The maximum number of digits that fit into the `u64` operands.
+const MAX_DIGITS: usize = 18;
+
+impl PluralOperands {
+    /// Parse the operands from the decimal representation of a number, e.g. `"-1.50"`.
+    ///
+    /// The fraction digits are significant, so `"1"` and `"1.0"` may select different categories.
+    /// Returns `None` if the input is not a plain decimal number.
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let abs = input.strip_prefix(['-', '+']).unwrap_or(input);
+        let (int_part, frac_part) = abs.split_once('.').unwrap_or((abs, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return None;
+        }
+        if !int_part.bytes().all(|b| b.is_ascii_digit())
+            || !frac_part.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let i = parse_digits(int_part);
+        let frac_part = &frac_part[..frac_part.len().min(MAX_DIGITS)];
+        let frac_trimmed = frac_part.trim_end_matches('0');
+
+        Some(Self {
+            n: abs.parse().ok()?,
+            i,
+            v: frac_part.len(),
+            w: frac_trimmed.len(),
+            f: parse_digits(frac_part),
+            t: parse_digits(frac_trimmed),
+        })
+    }
+
+    /// Returns the integer value of `n` if it has no (non-zero) fraction digits.
+    ///
+    /// Rules such as `n % 10 = 1` only match integers.
+    #[inline]
+    fn n_int(&self) -> Option<u64> {
+        if self.f == 0 {
+            Some(self.i)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parse the digits into a `u64`, keeping the lowest digits if the number is too long.
+///
+/// Too long numbers get a leading `1` so they never compare equal to small values.
+fn parse_digits(digits: &str) -> u64 {
+    if digits.is_empty() {
+        return 0;
+    }
+    if digits.len() <= MAX_DIGITS {
+        return digits.parse().unwrap_or_default();
+    }
+    let lowest = &digits[digits.len() - MAX_DIGITS..];
+    10u64.pow(MAX_DIGITS as u32) + lowest.parse::<u64>().unwrap_or_default()
+}
+
+macro_rules! impl_operands_from_integer {
+    ($($typ:ty),*) => {
+        $(
+            impl From<$typ> for PluralOperands {
+                fn from(val: $typ) -> Self {
+                    let i = val.unsigned_abs() as u64;
+                    Self {
+                        n: i as f64,
+                        i,
+                        ..Self::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_operands_from_unsigned {
+    ($($typ:ty),*) => {
+        $(
+            impl From<$typ> for PluralOperands {
+                fn from(val: $typ) -> Self {
+                    let i = val as u64;
+                    Self {
+                        n: i as f64,
+                        i,
+                        ..Self::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_operands_from_integer!(i8, i16, i32, i64, isize);
+impl_operands_from_unsigned!(u8, u16, u32, u64, usize);
+
+/// Returns the language subtag of a locale, e.g. `"pt"` for `"pt-BR"`.
+fn language(locale: &str) -> &str {
+    locale.split(['-', '_']).next().unwrap_or_default()
+}
+
+/// Returns the region subtag of a locale if present, e.g. `"BR"` for `"pt-BR"`.
+fn region(locale: &str) -> Option<&str> {
+    locale
+        .split(['-', '_'])
+        .skip(1)
+        .find(|s| s.len() == 2 || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit())))
+}
+
+/// Select the CLDR cardinal plural category of a number for the given locale.
+///
+/// The rules cover the languages of the CLDR plural rules except a few rare ones, such as `br`, `kw` and `mt`.
+/// The languages without a rule here only have the `other` category, so their translations must have an `other` form.
+///
+/// ```
+/// # use rust_i18n_support::{plural_category, PluralCategory, PluralOperands};
+/// let n = PluralOperands::from(3);
+/// assert_eq!(plural_category("en", &n), PluralCategory::Other);
+/// assert_eq!(plural_category("ru", &n), PluralCategory::Few);
+/// ```
+pub fn plural_category(locale: &str, operands: &PluralOperands) -> PluralCategory {
+    use PluralCategory::*;
+
+    let PluralOperands { n, i, v, f, t, .. } = *operands;
+    let in_range = |x: u64, range: std::ops::RangeInclusive<u64>| range.contains(&x);
+    // Many if `e = 0 and i != 0 and i % 1000000 = 0 and v = 0`, the exact millions.
+    let is_million = i != 0 && i % 1_000_000 == 0 && v == 0;
+
+    match language(locale).to_ascii_lowercase().as_str() {
+        // One if `i = 1 and v = 0`, many for the exact millions.
+        "ca" | "it" | "lij" | "sc" => {
+            if i == 1 && v == 0 {
+                One
+            } else if is_million {
+                Many
+            } else {
+                Other
+            }
+        }
+        // One if `i = 1 and v = 0`.
+        "ast" | "de" | "en" | "et" | "fi" | "fy" | "gl" | "ia" | "io" | "nl" | "sv" | "sw"
+        | "ur" | "yi" => {
+            if i == 1 && v == 0 {
+                One
+            } else {
+                Other
+            }
+        }
+        // One if `n = 1`, many for the exact millions.
+        "es" => {
+            if n == 1.0 {
+                One
+            } else if is_million {
+                Many
+            } else {
+                Other
+            }
+        }
+        // One if `n = 1`.
+        "af" | "az" | "bg" | "el" | "eo" | "eu" | "fo" | "hu" | "ka" | "kk" | "ky" | "lb"
+        | "ml" | "mn" | "nb" | "ne" | "nn" | "no" | "sq" | "ta" | "te" | "tk" | "tr" | "ug"
+        | "uz" => {
+            if n == 1.0 {
+                One
+            } else {
+                Other
+            }
+        }
+        "pt" if region(locale).is_some_and(|r| r.eq_ignore_ascii_case("PT")) => {
+            if i == 1 && v == 0 {
+                One
+            } else if is_million {
+                Many
+            } else {
+                Other
+            }
+        }
+        // One if `i = 0,1`, many for the exact millions.
+        "fr" | "pt" => {
+            if i <= 1 {
+                One
+            } else if is_million {
+                Many
+            } else {
+                Other
+            }
+        }
+        // One if `i = 0,1`.
+        "hy" | "kab" | "ff" => {
+            if i <= 1 {
+                One
+            } else {
+                Other
+            }
+        }
+        // One if `n = 0..1`.
+        "ak" | "bho" | "guw" | "ln" | "mg" | "nso" | "pa" | "ti" | "wa" => {
+            if n == 0.0 || n == 1.0 {
+                One
+            } else {
+                Other
+            }
+        }
+        "da" => {
+            if n == 1.0 || (t != 0 && i <= 1) {
+                One
+            } else {
+                Other
+            }
+        }
+        "is" => {
+            if (t == 0 && i % 10 == 1 && i % 100 != 11) || (t % 10 == 1 && t % 100 != 11) {
+                One
+            } else {
+                Other
+            }
+        }
+        "mk" => {
+            if (v == 0 && i % 10 == 1 && i % 100 != 11) || (f % 10 == 1 && f % 100 != 11) {
+                One
+            } else {
+                Other
+            }
+        }
+        "fil" | "tl" => {
+            let one = if v == 0 {
+                in_range(i, 1..=3) || ![4, 6, 9].contains(&(i % 10))
+            } else {
+                ![4, 6, 9].contains(&(f % 10))
+            };
+            if one {
+                One
+            } else {
+                Other
+            }
+        }
+        // One if `i = 0 or n = 1`.
+        "am" | "as" | "bn" | "fa" | "gu" | "hi" | "kn" | "mr" | "zu" => {
+            if i == 0 || n == 1.0 {
+                One
+            } else {
+                Other
+            }
+        }
+        "ru" | "uk" => {
+            if v != 0 {
+                Other
+            } else if i % 10 == 1 && i % 100 != 11 {
+                One
+            } else if in_range(i % 10, 2..=4) && !in_range(i % 100, 12..=14) {
+                Few
+            } else {
+                Many
+            }
+        }
+        "be" => match operands.n_int() {
+            Some(n) if n % 10 == 1 && n % 100 != 11 => One,
+            Some(n) if in_range(n % 10, 2..=4) && !in_range(n % 100, 12..=14) => Few,
+            Some(_) => Many,
+            None => Other,
+        },
+        "pl" => {
+            if i == 1 && v == 0 {
+                One
+            } else if v != 0 {
+                Other
+            } else if in_range(i % 10, 2..=4) && !in_range(i % 100, 12..=14) {
+                Few
+            } else {
+                Many
+            }
+        }
+        "cs" | "sk" => {
+            if v != 0 {
+                Many
+            } else if i == 1 {
+                One
+            } else if in_range(i, 2..=4) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "bs" | "hr" | "sr" => {
+            if (v == 0 && i % 10 == 1 && i % 100 != 11) || (f % 10 == 1 && f % 100 != 11) {
+                One
+            } else if (v == 0 && in_range(i % 10, 2..=4) && !in_range(i % 100, 12..=14))
+                || (in_range(f % 10, 2..=4) && !in_range(f % 100, 12..=14))
+            {
+                Few
+            } else {
+                Other
+            }
+        }
+        "lt" => match operands.n_int() {
+            _ if f != 0 => Many,
+            Some(n) if n % 10 == 1 && !in_range(n % 100, 11..=19) => One,
+            Some(n) if in_range(n % 10, 2..=9) && !in_range(n % 100, 11..=19) => Few,
+            _ => Other,
+        },
+        "ro" | "mo" => {
+            if i == 1 && v == 0 {
+                One
+            } else if v != 0
+                || n == 0.0
+                || operands
+                    .n_int()
+                    .is_some_and(|n| n != 1 && in_range(n % 100, 1..=19))
+            {
+                Few
+            } else {
+                Other
+            }
+        }
+        "lv" | "prg" => {
+            let n_int = operands.n_int();
+            if n_int.is_some_and(|n| n % 10 == 0 || in_range(n % 100, 11..=19))
+                || (v == 2 && in_range(f % 100, 11..=19))
+            {
+                Zero
+            } else if n_int.is_some_and(|n| n % 10 == 1 && n % 100 != 11)
+                || (f % 10 == 1 && (v != 2 || f % 100 != 11))
+            {
+                One
+            } else {
+                Other
+            }
+        }
+        "sl" => {
+            if v == 0 && i % 100 == 1 {
+                One
+            } else if v == 0 && i % 100 == 2 {
+                Two
+            } else if v != 0 || in_range(i % 100, 3..=4) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "he" | "iw" => {
+            if (i == 1 && v == 0) || (i == 0 && v != 0) {
+                One
+            } else if i == 2 && v == 0 {
+                Two
+            } else {
+                Other
+            }
+        }
+        "ar" | "ars" => match operands.n_int() {
+            Some(0) => Zero,
+            Some(1) => One,
+            Some(2) => Two,
+            Some(n) if in_range(n % 100, 3..=10) => Few,
+            Some(n) if in_range(n % 100, 11..=99) => Many,
+            _ => Other,
+        },
+        "cy" => match operands.n_int() {
+            Some(0) => Zero,
+            Some(1) => One,
+            Some(2) => Two,
+            Some(3) => Few,
+            Some(6) => Many,
+            _ => Other,
+        },
+        "ga" => match operands.n_int() {
+            Some(1) => One,
+            Some(2) => Two,
+            Some(3..=6) => Few,
+            Some(7..=10) => Many,
+            _ => Other,
+        },
+        "gd" => match operands.n_int() {
+            Some(1 | 11) => One,
+            Some(2 | 12) => Two,
+            Some(3..=10 | 13..=19) => Few,
+            _ => Other,
+        },
+        // No plural forms.
+        "bo" | "dz" | "id" | "ig" | "ii" | "in" | "ja" | "jv" | "km" | "ko" | "lo" | "ms"
+        | "my" | "sah" | "su" | "th" | "to" | "vi" | "wo" | "yo" | "yue" | "zh" => Other,
+        // Not supported, only the `other` form is used.
+        _ => Other,
+    }
+}
+
+/// Select the CLDR ordinal plural category of a number for the given locale.
+///
+/// Unknown locales only have the `other` category.
+///
+/// ```
+/// # use rust_i18n_support::{ordinal_category, PluralCategory, PluralOperands};
+/// let n = PluralOperands::from(22);
+/// assert_eq!(ordinal_category("en", &n), PluralCategory::Two);
+/// assert_eq!(ordinal_category("fr", &n), PluralCategory::Other);
+/// ```
+pub fn ordinal_category(locale: &str, operands: &PluralOperands) -> PluralCategory {
+    use PluralCategory::*;
+
+    let Some(n) = operands.n_int() else {
+        return Other;
+    };
+
+    match language(locale).to_ascii_lowercase().as_str() {
+        "en" => match (n % 10, n % 100) {
+            (1, m) if m != 11 => One,
+            (2, m) if m != 12 => Two,
+            (3, m) if m != 13 => Few,
+            _ => Other,
+        },
+        // One if `n = 1`.
+        "fil" | "fr" | "ga" | "hy" | "lo" | "mo" | "ms" | "ro" | "tl" | "vi" => {
+            if n == 1 {
+                One
+            } else {
+                Other
+            }
+        }
+        "it" | "sc" | "scn" => {
+            if [11, 8, 80, 800].contains(&n) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "sv" => {
+            if (n % 10 == 1 || n % 10 == 2) && n % 100 != 11 && n % 100 != 12 {
+                One
+            } else {
+                Other
+            }
+        }
+        "ca" => match n {
+            1 | 3 => One,
+            2 => Two,
+            4 => Few,
+            _ => Other,
+        },
+        "hu" => {
+            if n == 1 || n == 5 {
+                One
+            } else {
+                Other
+            }
+        }
+        "gu" | "hi" => match n {
+            1 => One,
+            2 | 3 => Two,
+            4 => Few,
+            6 => Many,
+            _ => Other,
+        },
+        "as" | "bn" => match n {
+            1 | 5 | 7 | 8 | 9 | 10 => One,
+            2 | 3 => Two,
+            4 => Few,
+            6 => Many,
+            _ => Other,
+        },
+        // Only the `other` form, e.g. "de", "ja", "ru", "zh".
+        _ => Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PluralCategory::*;
+
+    fn category(locale: &str, n: &str) -> PluralCategory {
+        plural_category(locale, &PluralOperands::parse(n).unwrap())
+    }
+
+    #[test]
+    fn test_parse_operands() {
+        let ops = PluralOperands::parse("-1.50").unwrap();
+        assert_eq!(ops.n, 1.5);
+        assert_eq!(ops.i, 1);
+        assert_eq!(ops.v, 2);
+        assert_eq!(ops.w, 1);
+        assert_eq!(ops.f, 50);
+        assert_eq!(ops.t, 5);
+
+        let ops = PluralOperands::parse("00000123").unwrap();
+        assert_eq!(ops.i, 123);
+        assert_eq!(ops.v, 0);
+
+        assert_eq!(PluralOperands::parse("42"), Some(PluralOperands::from(42)));
+        assert_eq!(
+            PluralOperands::parse("-7"),
+            Some(PluralOperands::from(-7i32))
+        );
+        assert_eq!(PluralOperands::parse(""), None);
+        assert_eq!(PluralOperands::parse("."), None);
+        assert_eq!(PluralOperands::parse("abc"), None);
+        assert_eq!(PluralOperands::parse("1e3"), None);
+
+        let ops = PluralOperands::parse("1000000000000000000001").unwrap();
+        assert_ne!(ops.i, 1);
+        assert_eq!(ops.i % 100, 1);
+    }
+
+    #[test]
+    fn test_plural_category() {
+        assert_eq!(category("en", "0"), Other);
+        assert_eq!(category("en", "1"), One);
+        assert_eq!(category("en", "1.0"), Other);
+        assert_eq!(category("en-US", "2"), Other);
+        assert_eq!(category("de", "1"), One);
+
+        assert_eq!(category("fr", "0"), One);
+        assert_eq!(category("fr", "1.5"), One);
+        assert_eq!(category("fr", "2"), Other);
+        assert_eq!(category("pt-BR", "0"), One);
+        assert_eq!(category("pt-PT", "0"), Other);
+        assert_eq!(category("pt_PT", "1"), One);
+
+        for locale in ["ja", "ko", "vi", "zh", "zh-CN", "zh-Hant-TW", "unknown"] {
+            assert_eq!(category(locale, "1"), Other);
+        }
+
+        assert_eq!(category("ru", "1"), One);
+        assert_eq!(category("ru", "21"), One);
+        assert_eq!(category("ru", "11"), Many);
+        assert_eq!(category("ru", "3"), Few);
+        assert_eq!(category("ru", "13"), Many);
+        assert_eq!(category("ru", "24"), Few);
+        assert_eq!(category("ru", "5"), Many);
+        assert_eq!(category("ru", "1.5"), Other);
+        assert_eq!(category("uk", "102"), Few);
+
+        assert_eq!(category("pl", "1"), One);
+        assert_eq!(category("pl", "21"), Many);
+        assert_eq!(category("pl", "22"), Few);
+        assert_eq!(category("pl", "12"), Many);
+        assert_eq!(category("pl", "0"), Many);
+        assert_eq!(category("pl", "0.5"), Other);
+
+        assert_eq!(category("cs", "1"), One);
+        assert_eq!(category("cs", "3"), Few);
+        assert_eq!(category("cs", "5"), Other);
+        assert_eq!(category("cs", "1.5"), Many);
+
+        assert_eq!(category("ar", "0"), Zero);
+        assert_eq!(category("ar", "1"), One);
+        assert_eq!(category("ar", "2"), Two);
+        assert_eq!(category("ar", "3"), Few);
+        assert_eq!(category("ar", "110"), Few);
+        assert_eq!(category("ar", "11"), Many);
+        assert_eq!(category("ar", "100"), Other);
+        assert_eq!(category("ar", "0.5"), Other);
+
+        assert_eq!(category("he", "2"), Two);
+        assert_eq!(category("lt", "21"), One);
+        assert_eq!(category("lt", "15"), Other);
+        assert_eq!(category("ro", "19"), Few);
+        assert_eq!(category("ro", "20"), Other);
+        assert_eq!(category("ro", "101"), Few);
+        assert_eq!(category("ro", "1001"), Few);
+        assert_eq!(category("ro", "1"), One);
+        assert_eq!(category("ro", "0"), Few);
+        assert_eq!(category("hr", "22"), Few);
+    }
+
+    #[test]
+    fn test_plural_category_many_millions() {
+        for locale in ["fr", "es", "it", "pt", "pt-PT", "ca"] {
+            assert_eq!(category(locale, "1000000"), Many, "{}", locale);
+            assert_eq!(category(locale, "3000000"), Many, "{}", locale);
+            assert_eq!(category(locale, "1000001"), Other, "{}", locale);
+            assert_eq!(category(locale, "1000000.5"), Other, "{}", locale);
+            assert_eq!(category(locale, "2"), Other, "{}", locale);
+        }
+        assert_eq!(category("fr", "0"), One);
+        assert_eq!(category("es", "1"), One);
+        assert_eq!(category("en", "1000000"), Other);
+    }
+
+    #[test]
+    fn test_plural_category_more_locales() {
+        assert_eq!(category("da", "1"), One);
+        assert_eq!(category("da", "0.1"), One);
+        assert_eq!(category("da", "2"), Other);
+
+        assert_eq!(category("is", "21"), One);
+        assert_eq!(category("is", "11"), Other);
+        assert_eq!(category("is", "0.1"), One);
+
+        assert_eq!(category("lv", "0"), Zero);
+        assert_eq!(category("lv", "15"), Zero);
+        assert_eq!(category("lv", "21"), One);
+        assert_eq!(category("lv", "2"), Other);
+
+        assert_eq!(category("sl", "101"), One);
+        assert_eq!(category("sl", "2"), Two);
+        assert_eq!(category("sl", "4"), Few);
+        assert_eq!(category("sl", "1.5"), Few);
+        assert_eq!(category("sl", "5"), Other);
+
+        assert_eq!(category("be", "21"), One);
+        assert_eq!(category("be", "23"), Few);
+        assert_eq!(category("be", "12"), Many);
+        assert_eq!(category("be", "1.5"), Other);
+
+        assert_eq!(category("cy", "0"), Zero);
+        assert_eq!(category("cy", "3"), Few);
+        assert_eq!(category("cy", "6"), Many);
+        assert_eq!(category("cy", "4"), Other);
+
+        assert_eq!(category("ga", "2"), Two);
+        assert_eq!(category("ga", "5"), Few);
+        assert_eq!(category("ga", "8"), Many);
+        assert_eq!(category("ga", "11"), Other);
+
+        assert_eq!(category("gd", "11"), One);
+        assert_eq!(category("gd", "12"), Two);
+        assert_eq!(category("gd", "15"), Few);
+        assert_eq!(category("gd", "20"), Other);
+
+        assert_eq!(category("mk", "11"), Other);
+        assert_eq!(category("mk", "21"), One);
+        assert_eq!(category("fil", "4"), Other);
+        assert_eq!(category("fil", "5"), One);
+        assert_eq!(category("pa", "0"), One);
+        assert_eq!(category("pa", "0.5"), Other);
+    }
+
+    #[test]
+    fn test_ordinal_category() {
+        let ordinal = |locale: &str, n: &str| {
+            PluralRuleType::Ordinal.category(locale, &PluralOperands::parse(n).unwrap())
+        };
+
+        assert_eq!(ordinal("en", "1"), One);
+        assert_eq!(ordinal("en", "2"), Two);
+        assert_eq!(ordinal("en", "3"), Few);
+        assert_eq!(ordinal("en", "4"), Other);
+        assert_eq!(ordinal("en", "11"), Other);
+        assert_eq!(ordinal("en", "12"), Other);
+        assert_eq!(ordinal("en", "13"), Other);
+        assert_eq!(ordinal("en-GB", "101"), One);
+        assert_eq!(ordinal("en", "112"), Other);
+        assert_eq!(ordinal("en", "1.5"), Other);
+
+        assert_eq!(ordinal("fr", "1"), One);
+        assert_eq!(ordinal("fr", "2"), Other);
+        assert_eq!(ordinal("it", "8"), Many);
+        assert_eq!(ordinal("sv", "22"), One);
+        assert_eq!(ordinal("ca", "4"), Few);
+        assert_eq!(ordinal("hi", "6"), Many);
+        assert_eq!(ordinal("de", "1"), Other);
+        assert_eq!(ordinal("zh-CN", "1"), Other);
+
+        assert_eq!(PluralRuleType::Cardinal.category("en", &1.into()), One);
+        assert_eq!(PluralRuleType::Cardinal.key_prefix(), "");
+        assert_eq!(PluralRuleType::Ordinal.key_prefix(), "ordinal.");
+    }
+}
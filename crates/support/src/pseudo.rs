@@ -0,0 +1,298 @@
+use crate::find_links;
+
+/// The accented and expanded pseudo-locale, to find the hard-coded strings and the truncation bugs.
+pub const PSEUDO_LOCALE_ACCENTED: &str = "en-XA";
+/// The mirrored right-to-left pseudo-locale, to find the layout bugs of the RTL languages.
+pub const PSEUDO_LOCALE_BIDI: &str = "ar-XB";
+/// The pseudo-locales generated by [`pseudo_localize`].
+pub const PSEUDO_LOCALES: &[&str] = &[PSEUDO_LOCALE_ACCENTED, PSEUDO_LOCALE_BIDI];
+
+/// The filler to pad the accented text, so it's not mistaken for a part of the text.
+const PADDING: char = '~';
+
+/// Pseudo-localize a translation for the pseudo-locale, returns `None` if the locale is not a pseudo-locale.
+///
+/// - `en-XA`: the letters are accented, the length is padded by about 40% with `~`, and the text is wrapped in `[` and `]`,
+///   such as `Hello` -> `[Ĥéļļö ~~]`.
+/// - `ar-XB`: each word is wrapped in the right-to-left override characters, so it's displayed mirrored.
+///
+/// The `%{name}` placeholders, the `{...}` ICU arguments and the `@:key` links are kept as-is,
+/// except the messages of the `plural`, `selectordinal` and `select` branches, which are pseudo-localized.
+///
+/// ```
+/// # use rust_i18n_support::pseudo_localize;
+/// assert_eq!(pseudo_localize("en-XA", "Hello, %{name}!").unwrap(), "[Ĥéļļö, %{name}! ~~~~]");
+/// assert_eq!(
+///     pseudo_localize("en-XA", "{n, plural, one {# file} other {# files}}").unwrap(),
+///     "[{n, plural, one {# ƒîļé} other {# ƒîļéš}} ~~~~~~]"
+/// );
+/// assert_eq!(pseudo_localize("en", "Hello"), None);
+/// ```
+pub fn pseudo_localize(locale: &str, text: &str) -> Option<String> {
+    match locale {
+        PSEUDO_LOCALE_ACCENTED => Some(accent(text)),
+        PSEUDO_LOCALE_BIDI => Some(mirror(text)),
+        _ => None,
+    }
+}
+
+fn accent(text: &str) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut len = 0usize;
+    result.push('[');
+    for (is_text, part) in split_protected(text) {
+        if !is_text {
+            result.push_str(part);
+            continue;
+        }
+        for c in part.chars() {
+            result.push(accent_char(c));
+            len += 1;
+        }
+    }
+
+    let padding = (len * 2 + 4) / 5;
+    if padding > 0 {
+        result.push(' ');
+        result.extend(std::iter::repeat(PADDING).take(padding));
+    }
+    result.push(']');
+    result
+}
+
+fn mirror(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() * 2);
+    for (is_text, part) in split_protected(text) {
+        if !is_text {
+            result.push_str(part);
+            continue;
+        }
+        let mut in_word = false;
+        for c in part.chars() {
+            if c.is_whitespace() == in_word {
+                result.push_str(if in_word {
+                    "\u{202C}\u{200F}"
+                } else {
+                    "\u{200F}\u{202E}"
+                });
+                in_word = !in_word;
+            }
+            result.push(c);
+        }
+        if in_word {
+            result.push_str("\u{202C}\u{200F}");
+        }
+    }
+    result
+}
+
+fn accent_char(c: char) -> char {
+    match c {
+        'a' => 'á',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ð',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'î',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ɱ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Đ',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Î',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        _ => c,
+    }
+}
+
+/// Split the text into the parts to pseudo-localize (`true`) and the parts to keep (`false`),
+/// the kept parts are the links, the `{...}` groups with the `%` before them,
+/// and the ICU arguments except the messages of their branches.
+fn split_protected(text: &str) -> Vec<(bool, &str)> {
+    let links = find_links(text);
+    let bytes = text.as_bytes();
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let end = if let Some(link) = links.iter().find(|link| link.range.start == i) {
+            Some(link.range.end)
+        } else if matches!(bytes[i], b'%' | b'{') {
+            let brace = i + bytes[i..].iter().take_while(|b| **b == b'%').count();
+            matching_brace(bytes, brace).map(|end| end + 1)
+        } else {
+            None
+        };
+
+        match end {
+            Some(end) => {
+                if start < i {
+                    parts.push((true, &text[start..i]));
+                }
+                if bytes[i] == b'{' {
+                    split_argument(text, i, end - 1, &mut parts);
+                } else {
+                    parts.push((false, &text[i..end]));
+                }
+                start = end;
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+    if start < text.len() {
+        parts.push((true, &text[start..]));
+    }
+    parts
+}
+
+/// Split the ICU argument from the `{` at `start` to the `}` at `end`, the messages of the `plural`, `selectordinal`
+/// and `select` branches are split like the text, and the rest are kept, such as `{count, plural, one {` and `} other {`.
+fn split_argument<'a>(text: &'a str, start: usize, end: usize, parts: &mut Vec<(bool, &'a str)>) {
+    let mut fields = text[start + 1..end].splitn(3, ',');
+    let (Some(name), Some(kind), Some(_)) = (fields.next(), fields.next(), fields.next()) else {
+        parts.push((false, &text[start..=end]));
+        return;
+    };
+    if name.contains('{') || !matches!(kind.trim(), "plural" | "selectordinal" | "select") {
+        parts.push((false, &text[start..=end]));
+        return;
+    }
+
+    let bytes = text.as_bytes();
+    let mut kept = start;
+    let mut i = start + 1 + name.len() + 1 + kind.len() + 1;
+    while i < end {
+        if bytes[i] != b'{' {
+            i += 1;
+            continue;
+        }
+        let Some(close) = matching_brace(bytes, i) else {
+            break;
+        };
+        parts.push((false, &text[kept..=i]));
+        parts.extend(split_protected(&text[i + 1..close]));
+        kept = close;
+        i = close + 1;
+    }
+    parts.push((false, &text[kept..=end]));
+}
+
+/// Find the `}` matching the `{` at `start`.
+fn matching_brace(bytes: &[u8], start: usize) -> Option<usize> {
+    if bytes.get(start) != Some(&b'{') {
+        return None;
+    }
+    let mut depth = 0;
+    for (i, b) in bytes.iter().enumerate().skip(start) {
+        match b {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pseudo_localize_accented() {
+        let localize = |text| pseudo_localize("en-XA", text).unwrap();
+        assert_eq!(localize(""), "");
+        assert_eq!(localize("Hello"), "[Ĥéļļö ~~]");
+        assert_eq!(localize("Hello world"), "[Ĥéļļö ŵöŕļð ~~~~~]");
+        assert_eq!(localize("%{count} items"), "[%{count} îţéɱš ~~~]");
+        assert_eq!(localize("Use %%{name}"), "[Ûšé %%{name} ~~]");
+        assert_eq!(
+            localize("Welcome to @:brand.name!"),
+            "[Ŵéļçöɱé ţö @:brand.name! ~~~~~]"
+        );
+        assert_eq!(
+            localize("{count, plural, one {# item} other {# items}}"),
+            "[{count, plural, one {# îţéɱ} other {# îţéɱš}} ~~~~~~]"
+        );
+        assert_eq!(
+            localize("{count, plural, offset:1 =0 {No one} other {{name} and # more}}"),
+            "[{count, plural, offset:1 =0 {Ñö öñé} other {{name} áñð # ɱöŕé}} ~~~~~~~]"
+        );
+        assert_eq!(
+            localize("{gender, select, female {She} other {They}} liked {count, number}"),
+            "[{gender, select, female {Šĥé} other {Ţĥéý}} ļîķéð {count, number} ~~~~~~]"
+        );
+        // Unbalanced braces are text
+        assert_eq!(localize("a { b"), "[á { ƀ ~~]");
+        assert_eq!(localize("100%"), "[100% ~~]");
+    }
+
+    #[test]
+    fn test_pseudo_localize_bidi() {
+        let localize = |text| pseudo_localize("ar-XB", text).unwrap();
+        assert_eq!(localize(""), "");
+        assert_eq!(localize("Hello"), "\u{200F}\u{202E}Hello\u{202C}\u{200F}");
+        assert_eq!(
+            localize("Hi %{name} ok"),
+            "\u{200F}\u{202E}Hi\u{202C}\u{200F} %{name} \u{200F}\u{202E}ok\u{202C}\u{200F}"
+        );
+        assert_eq!(
+            localize("{n, plural, other {Hi {name}}}"),
+            "{n, plural, other {\u{200F}\u{202E}Hi\u{202C}\u{200F} {name}}}"
+        );
+    }
+
+    #[test]
+    fn test_pseudo_localize_other_locales() {
+        assert_eq!(pseudo_localize("en", "Hello"), None);
+        assert_eq!(pseudo_localize("ar", "Hello"), None);
+    }
+}
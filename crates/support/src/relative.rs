@@ -0,0 +1,978 @@
+use std::time::{Duration, SystemTimeError};
+
+use crate::locale::locale_entry;
+use crate::number::parse_decimal;
+use crate::{format_number, plural_category, NumberStyle, PluralCategory, PluralOperands};
+
+/// The plural forms of a pattern, in the CLDR order of `zero`, `one`, `two`, `few`, `many` and `other`,
+/// the empty forms use `other`.
+///
+/// `{0}` is replaced with the number.
+type Forms = [&'static str; 6];
+
+const fn forms(one: &'static str, other: &'static str) -> Forms {
+    ["", one, "", "", "", other]
+}
+
+/// The relative time patterns of a unit.
+struct RelativeUnit {
+    future: Forms,
+    past: Forms,
+}
+
+/// The CLDR relative time patterns of a locale.
+struct RelativeSymbols {
+    now: &'static str,
+    /// The units from second, minute, hour, day, week, month to year.
+    units: [RelativeUnit; 7],
+    /// The duration units from day, hour, minute to second.
+    duration: [Forms; 4],
+    /// The separator between the duration units.
+    duration_separator: &'static str,
+}
+
+/// The seconds of the units of [`RelativeSymbols::units`], a month is 30 days and a year is 365 days.
+const UNIT_SECONDS: [u64; 7] = [1, 60, 3600, 86400, 7 * 86400, 30 * 86400, 365 * 86400];
+/// The seconds of the units of [`RelativeSymbols::duration`].
+const DURATION_SECONDS: [u64; 4] = [86400, 3600, 60, 1];
+
+const fn unit(future: Forms, past: Forms) -> RelativeUnit {
+    RelativeUnit { future, past }
+}
+
+/// The units with the same `one` and `other` forms, such as in Chinese.
+const fn same(future: &'static str, past: &'static str) -> RelativeUnit {
+    unit(forms(future, future), forms(past, past))
+}
+
+const EN: RelativeSymbols = RelativeSymbols {
+    now: "now",
+    units: [
+        unit(
+            forms("in {0} second", "in {0} seconds"),
+            forms("{0} second ago", "{0} seconds ago"),
+        ),
+        unit(
+            forms("in {0} minute", "in {0} minutes"),
+            forms("{0} minute ago", "{0} minutes ago"),
+        ),
+        unit(
+            forms("in {0} hour", "in {0} hours"),
+            forms("{0} hour ago", "{0} hours ago"),
+        ),
+        unit(
+            forms("in {0} day", "in {0} days"),
+            forms("{0} day ago", "{0} days ago"),
+        ),
+        unit(
+            forms("in {0} week", "in {0} weeks"),
+            forms("{0} week ago", "{0} weeks ago"),
+        ),
+        unit(
+            forms("in {0} month", "in {0} months"),
+            forms("{0} month ago", "{0} months ago"),
+        ),
+        unit(
+            forms("in {0} year", "in {0} years"),
+            forms("{0} year ago", "{0} years ago"),
+        ),
+    ],
+    duration: [
+        forms("{0} d", "{0} d"),
+        forms("{0} h", "{0} h"),
+        forms("{0} min", "{0} min"),
+        forms("{0} s", "{0} s"),
+    ],
+    duration_separator: " ",
+};
+
+/// The relative time patterns of the locales, from CLDR, the other locales use [`EN`].
+const LOCALE_SYMBOLS: &[(&str, RelativeSymbols)] = &[
+    (
+        "ar",
+        RelativeSymbols {
+            now: "الآن",
+            units: [
+                unit(
+                    [
+                        "خلال {0} ثانية",
+                        "خلال ثانية واحدة",
+                        "خلال ثانيتين",
+                        "خلال {0} ثوانٍ",
+                        "خلال {0} ثانية",
+                        "خلال {0} ثانية",
+                    ],
+                    [
+                        "قبل {0} ثانية",
+                        "قبل ثانية واحدة",
+                        "قبل ثانيتين",
+                        "قبل {0} ثوانٍ",
+                        "قبل {0} ثانية",
+                        "قبل {0} ثانية",
+                    ],
+                ),
+                unit(
+                    [
+                        "خلال {0} دقيقة",
+                        "خلال دقيقة واحدة",
+                        "خلال دقيقتين",
+                        "خلال {0} دقائق",
+                        "خلال {0} دقيقة",
+                        "خلال {0} دقيقة",
+                    ],
+                    [
+                        "قبل {0} دقيقة",
+                        "قبل دقيقة واحدة",
+                        "قبل دقيقتين",
+                        "قبل {0} دقائق",
+                        "قبل {0} دقيقة",
+                        "قبل {0} دقيقة",
+                    ],
+                ),
+                unit(
+                    [
+                        "خلال {0} ساعة",
+                        "خلال ساعة واحدة",
+                        "خلال ساعتين",
+                        "خلال {0} ساعات",
+                        "خلال {0} ساعة",
+                        "خلال {0} ساعة",
+                    ],
+                    [
+                        "قبل {0} ساعة",
+                        "قبل ساعة واحدة",
+                        "قبل ساعتين",
+                        "قبل {0} ساعات",
+                        "قبل {0} ساعة",
+                        "قبل {0} ساعة",
+                    ],
+                ),
+                unit(
+                    [
+                        "خلال {0} يوم",
+                        "خلال يوم واحد",
+                        "خلال يومين",
+                        "خلال {0} أيام",
+                        "خلال {0} يومًا",
+                        "خلال {0} يوم",
+                    ],
+                    [
+                        "قبل {0} يوم",
+                        "قبل يوم واحد",
+                        "قبل يومين",
+                        "قبل {0} أيام",
+                        "قبل {0} يومًا",
+                        "قبل {0} يوم",
+                    ],
+                ),
+                unit(
+                    [
+                        "خلال {0} أسبوع",
+                        "خلال أسبوع واحد",
+                        "خلال أسبوعين",
+                        "خلال {0} أسابيع",
+                        "خلال {0} أسبوعًا",
+                        "خلال {0} أسبوع",
+                    ],
+                    [
+                        "قبل {0} أسبوع",
+                        "قبل أسبوع واحد",
+                        "قبل أسبوعين",
+                        "قبل {0} أسابيع",
+                        "قبل {0} أسبوعًا",
+                        "قبل {0} أسبوع",
+                    ],
+                ),
+                unit(
+                    [
+                        "خلال {0} شهر",
+                        "خلال شهر واحد",
+                        "خلال شهرين",
+                        "خلال {0} أشهر",
+                        "خلال {0} شهرًا",
+                        "خلال {0} شهر",
+                    ],
+                    [
+                        "قبل {0} شهر",
+                        "قبل شهر واحد",
+                        "قبل شهرين",
+                        "قبل {0} أشهر",
+                        "قبل {0} شهرًا",
+                        "قبل {0} شهر",
+                    ],
+                ),
+                unit(
+                    [
+                        "خلال {0} سنة",
+                        "خلال سنة واحدة",
+                        "خلال سنتين",
+                        "خلال {0} سنوات",
+                        "خلال {0} سنة",
+                        "خلال {0} سنة",
+                    ],
+                    [
+                        "قبل {0} سنة",
+                        "قبل سنة واحدة",
+                        "قبل سنتين",
+                        "قبل {0} سنوات",
+                        "قبل {0} سنة",
+                        "قبل {0} سنة",
+                    ],
+                ),
+            ],
+            duration: [
+                forms("{0} ي", "{0} ي"),
+                forms("{0} س", "{0} س"),
+                forms("{0} د", "{0} د"),
+                forms("{0} ث", "{0} ث"),
+            ],
+            duration_separator: " ",
+        },
+    ),
+    (
+        "de",
+        RelativeSymbols {
+            now: "jetzt",
+            units: [
+                unit(
+                    forms("in {0} Sekunde", "in {0} Sekunden"),
+                    forms("vor {0} Sekunde", "vor {0} Sekunden"),
+                ),
+                unit(
+                    forms("in {0} Minute", "in {0} Minuten"),
+                    forms("vor {0} Minute", "vor {0} Minuten"),
+                ),
+                unit(
+                    forms("in {0} Stunde", "in {0} Stunden"),
+                    forms("vor {0} Stunde", "vor {0} Stunden"),
+                ),
+                unit(
+                    forms("in {0} Tag", "in {0} Tagen"),
+                    forms("vor {0} Tag", "vor {0} Tagen"),
+                ),
+                unit(
+                    forms("in {0} Woche", "in {0} Wochen"),
+                    forms("vor {0} Woche", "vor {0} Wochen"),
+                ),
+                unit(
+                    forms("in {0} Monat", "in {0} Monaten"),
+                    forms("vor {0} Monat", "vor {0} Monaten"),
+                ),
+                unit(
+                    forms("in {0} Jahr", "in {0} Jahren"),
+                    forms("vor {0} Jahr", "vor {0} Jahren"),
+                ),
+            ],
+            duration: [
+                forms("{0} T.", "{0} T."),
+                forms("{0} Std.", "{0} Std."),
+                forms("{0} Min.", "{0} Min."),
+                forms("{0} Sek.", "{0} Sek."),
+            ],
+            duration_separator: " ",
+        },
+    ),
+    (
+        "es",
+        RelativeSymbols {
+            now: "ahora",
+            units: [
+                unit(
+                    forms("dentro de {0} segundo", "dentro de {0} segundos"),
+                    forms("hace {0} segundo", "hace {0} segundos"),
+                ),
+                unit(
+                    forms("dentro de {0} minuto", "dentro de {0} minutos"),
+                    forms("hace {0} minuto", "hace {0} minutos"),
+                ),
+                unit(
+                    forms("dentro de {0} hora", "dentro de {0} horas"),
+                    forms("hace {0} hora", "hace {0} horas"),
+                ),
+                unit(
+                    forms("dentro de {0} día", "dentro de {0} días"),
+                    forms("hace {0} día", "hace {0} días"),
+                ),
+                unit(
+                    forms("dentro de {0} semana", "dentro de {0} semanas"),
+                    forms("hace {0} semana", "hace {0} semanas"),
+                ),
+                unit(
+                    forms("dentro de {0} mes", "dentro de {0} meses"),
+                    forms("hace {0} mes", "hace {0} meses"),
+                ),
+                unit(
+                    forms("dentro de {0} año", "dentro de {0} años"),
+                    forms("hace {0} año", "hace {0} años"),
+                ),
+            ],
+            duration: [
+                forms("{0} d", "{0} d"),
+                forms("{0} h", "{0} h"),
+                forms("{0} min", "{0} min"),
+                forms("{0} s", "{0} s"),
+            ],
+            duration_separator: " ",
+        },
+    ),
+    (
+        "fr",
+        RelativeSymbols {
+            now: "maintenant",
+            units: [
+                unit(
+                    forms("dans {0} seconde", "dans {0} secondes"),
+                    forms("il y a {0} seconde", "il y a {0} secondes"),
+                ),
+                unit(
+                    forms("dans {0} minute", "dans {0} minutes"),
+                    forms("il y a {0} minute", "il y a {0} minutes"),
+                ),
+                unit(
+                    forms("dans {0} heure", "dans {0} heures"),
+                    forms("il y a {0} heure", "il y a {0} heures"),
+                ),
+                unit(
+                    forms("dans {0} jour", "dans {0} jours"),
+                    forms("il y a {0} jour", "il y a {0} jours"),
+                ),
+                unit(
+                    forms("dans {0} semaine", "dans {0} semaines"),
+                    forms("il y a {0} semaine", "il y a {0} semaines"),
+                ),
+                same("dans {0} mois", "il y a {0} mois"),
+                unit(
+                    forms("dans {0} an", "dans {0} ans"),
+                    forms("il y a {0} an", "il y a {0} ans"),
+                ),
+            ],
+            duration: [
+                forms("{0} j", "{0} j"),
+                forms("{0} h", "{0} h"),
+                forms("{0} min", "{0} min"),
+                forms("{0} s", "{0} s"),
+            ],
+            duration_separator: " ",
+        },
+    ),
+    (
+        "he",
+        RelativeSymbols {
+            now: "עכשיו",
+            units: [
+                unit(
+                    ["", "בעוד שנייה", "בעוד שתי שניות", "", "", "בעוד {0} שניות"],
+                    ["", "לפני שנייה", "לפני שתי שניות", "", "", "לפני {0} שניות"],
+                ),
+                unit(
+                    ["", "בעוד דקה", "בעוד שתי דקות", "", "", "בעוד {0} דקות"],
+                    ["", "לפני דקה", "לפני שתי דקות", "", "", "לפני {0} דקות"],
+                ),
+                unit(
+                    ["", "בעוד שעה", "בעוד שעתיים", "", "", "בעוד {0} שעות"],
+                    ["", "לפני שעה", "לפני שעתיים", "", "", "לפני {0} שעות"],
+                ),
+                unit(
+                    ["", "בעוד יום", "בעוד יומיים", "", "", "בעוד {0} ימים"],
+                    ["", "לפני יום", "לפני יומיים", "", "", "לפני {0} ימים"],
+                ),
+                unit(
+                    ["", "בעוד שבוע", "בעוד שבועיים", "", "", "בעוד {0} שבועות"],
+                    ["", "לפני שבוע", "לפני שבועיים", "", "", "לפני {0} שבועות"],
+                ),
+                unit(
+                    ["", "בעוד חודש", "בעוד חודשיים", "", "", "בעוד {0} חודשים"],
+                    ["", "לפני חודש", "לפני חודשיים", "", "", "לפני {0} חודשים"],
+                ),
+                unit(
+                    ["", "בעוד שנה", "בעוד שנתיים", "", "", "בעוד {0} שנים"],
+                    ["", "לפני שנה", "לפני שנתיים", "", "", "לפני {0} שנים"],
+                ),
+            ],
+            duration: [
+                forms("{0} ימ׳", "{0} ימ׳"),
+                forms("{0} שע׳", "{0} שע׳"),
+                forms("{0} דק׳", "{0} דק׳"),
+                forms("{0} שנ׳", "{0} שנ׳"),
+            ],
+            duration_separator: " ",
+        },
+    ),
+    (
+        "it",
+        RelativeSymbols {
+            now: "ora",
+            units: [
+                unit(
+                    forms("tra {0} secondo", "tra {0} secondi"),
+                    forms("{0} secondo fa", "{0} secondi fa"),
+                ),
+                unit(
+                    forms("tra {0} minuto", "tra {0} minuti"),
+                    forms("{0} minuto fa", "{0} minuti fa"),
+                ),
+                unit(
+                    forms("tra {0} ora", "tra {0} ore"),
+                    forms("{0} ora fa", "{0} ore fa"),
+                ),
+                unit(
+                    forms("tra {0} giorno", "tra {0} giorni"),
+                    forms("{0} giorno fa", "{0} giorni fa"),
+                ),
+                unit(
+                    forms("tra {0} settimana", "tra {0} settimane"),
+                    forms("{0} settimana fa", "{0} settimane fa"),
+                ),
+                unit(
+                    forms("tra {0} mese", "tra {0} mesi"),
+                    forms("{0} mese fa", "{0} mesi fa"),
+                ),
+                unit(
+                    forms("tra {0} anno", "tra {0} anni"),
+                    forms("{0} anno fa", "{0} anni fa"),
+                ),
+            ],
+            duration: [
+                forms("{0} g", "{0} g"),
+                forms("{0} h", "{0} h"),
+                forms("{0} min", "{0} min"),
+                forms("{0} s", "{0} s"),
+            ],
+            duration_separator: " ",
+        },
+    ),
+    (
+        "ja",
+        RelativeSymbols {
+            now: "今",
+            units: [
+                same("{0} 秒後", "{0} 秒前"),
+                same("{0} 分後", "{0} 分前"),
+                same("{0} 時間後", "{0} 時間前"),
+                same("{0} 日後", "{0} 日前"),
+                same("{0} 週間後", "{0} 週間前"),
+                same("{0} か月後", "{0} か月前"),
+                same("{0} 年後", "{0} 年前"),
+            ],
+            duration: [
+                forms("{0}日", "{0}日"),
+                forms("{0}時間", "{0}時間"),
+                forms("{0}分", "{0}分"),
+                forms("{0}秒", "{0}秒"),
+            ],
+            duration_separator: "",
+        },
+    ),
+    (
+        "ko",
+        RelativeSymbols {
+            now: "지금",
+            units: [
+                same("{0}초 후", "{0}초 전"),
+                same("{0}분 후", "{0}분 전"),
+                same("{0}시간 후", "{0}시간 전"),
+                same("{0}일 후", "{0}일 전"),
+                same("{0}주 후", "{0}주 전"),
+                same("{0}개월 후", "{0}개월 전"),
+                same("{0}년 후", "{0}년 전"),
+            ],
+            duration: [
+                forms("{0}일", "{0}일"),
+                forms("{0}시간", "{0}시간"),
+                forms("{0}분", "{0}분"),
+                forms("{0}초", "{0}초"),
+            ],
+            duration_separator: " ",
+        },
+    ),
+    (
+        "pt",
+        RelativeSymbols {
+            now: "agora",
+            units: [
+                unit(
+                    forms("em {0} segundo", "em {0} segundos"),
+                    forms("há {0} segundo", "há {0} segundos"),
+                ),
+                unit(
+                    forms("em {0} minuto", "em {0} minutos"),
+                    forms("há {0} minuto", "há {0} minutos"),
+                ),
+                unit(
+                    forms("em {0} hora", "em {0} horas"),
+                    forms("há {0} hora", "há {0} horas"),
+                ),
+                unit(
+                    forms("em {0} dia", "em {0} dias"),
+                    forms("há {0} dia", "há {0} dias"),
+                ),
+                unit(
+                    forms("em {0} semana", "em {0} semanas"),
+                    forms("há {0} semana", "há {0} semanas"),
+                ),
+                unit(
+                    forms("em {0} mês", "em {0} meses"),
+                    forms("há {0} mês", "há {0} meses"),
+                ),
+                unit(
+                    forms("em {0} ano", "em {0} anos"),
+                    forms("há {0} ano", "há {0} anos"),
+                ),
+            ],
+            duration: [
+                forms("{0} dia", "{0} dias"),
+                forms("{0} h", "{0} h"),
+                forms("{0} min", "{0} min"),
+                forms("{0} s", "{0} s"),
+            ],
+            duration_separator: " ",
+        },
+    ),
+    (
+        "ru",
+        RelativeSymbols {
+            now: "сейчас",
+            units: [
+                unit(
+                    [
+                        "",
+                        "через {0} секунду",
+                        "",
+                        "через {0} секунды",
+                        "через {0} секунд",
+                        "через {0} секунды",
+                    ],
+                    [
+                        "",
+                        "{0} секунду назад",
+                        "",
+                        "{0} секунды назад",
+                        "{0} секунд назад",
+                        "{0} секунды назад",
+                    ],
+                ),
+                unit(
+                    [
+                        "",
+                        "через {0} минуту",
+                        "",
+                        "через {0} минуты",
+                        "через {0} минут",
+                        "через {0} минуты",
+                    ],
+                    [
+                        "",
+                        "{0} минуту назад",
+                        "",
+                        "{0} минуты назад",
+                        "{0} минут назад",
+                        "{0} минуты назад",
+                    ],
+                ),
+                unit(
+                    [
+                        "",
+                        "через {0} час",
+                        "",
+                        "через {0} часа",
+                        "через {0} часов",
+                        "через {0} часа",
+                    ],
+                    [
+                        "",
+                        "{0} час назад",
+                        "",
+                        "{0} часа назад",
+                        "{0} часов назад",
+                        "{0} часа назад",
+                    ],
+                ),
+                unit(
+                    [
+                        "",
+                        "через {0} день",
+                        "",
+                        "через {0} дня",
+                        "через {0} дней",
+                        "через {0} дня",
+                    ],
+                    [
+                        "",
+                        "{0} день назад",
+                        "",
+                        "{0} дня назад",
+                        "{0} дней назад",
+                        "{0} дня назад",
+                    ],
+                ),
+                unit(
+                    [
+                        "",
+                        "через {0} неделю",
+                        "",
+                        "через {0} недели",
+                        "через {0} недель",
+                        "через {0} недели",
+                    ],
+                    [
+                        "",
+                        "{0} неделю назад",
+                        "",
+                        "{0} недели назад",
+                        "{0} недель назад",
+                        "{0} недели назад",
+                    ],
+                ),
+                unit(
+                    [
+                        "",
+                        "через {0} месяц",
+                        "",
+                        "через {0} месяца",
+                        "через {0} месяцев",
+                        "через {0} месяца",
+                    ],
+                    [
+                        "",
+                        "{0} месяц назад",
+                        "",
+                        "{0} месяца назад",
+                        "{0} месяцев назад",
+                        "{0} месяца назад",
+                    ],
+                ),
+                unit(
+                    [
+                        "",
+                        "через {0} год",
+                        "",
+                        "через {0} года",
+                        "через {0} лет",
+                        "через {0} года",
+                    ],
+                    [
+                        "",
+                        "{0} год назад",
+                        "",
+                        "{0} года назад",
+                        "{0} лет назад",
+                        "{0} года назад",
+                    ],
+                ),
+            ],
+            duration: [
+                forms("{0} дн.", "{0} дн."),
+                forms("{0} ч", "{0} ч"),
+                forms("{0} мин", "{0} мин"),
+                forms("{0} с", "{0} с"),
+            ],
+            duration_separator: " ",
+        },
+    ),
+    (
+        "zh",
+        RelativeSymbols {
+            now: "现在",
+            units: [
+                same("{0}秒钟后", "{0}秒钟前"),
+                same("{0}分钟后", "{0}分钟前"),
+                same("{0}小时后", "{0}小时前"),
+                same("{0}天后", "{0}天前"),
+                same("{0}周后", "{0}周前"),
+                same("{0}个月后", "{0}个月前"),
+                same("{0}年后", "{0}年前"),
+            ],
+            duration: [
+                forms("{0}天", "{0}天"),
+                forms("{0}小时", "{0}小时"),
+                forms("{0}分钟", "{0}分钟"),
+                forms("{0}秒", "{0}秒"),
+            ],
+            duration_separator: "",
+        },
+    ),
+];
+
+/// Find the symbols of the locale, or of its language, such as `pt-BR` -> `pt`.
+fn relative_symbols(locale: &str) -> &'static RelativeSymbols {
+    locale_entry(LOCALE_SYMBOLS, locale).unwrap_or(&EN)
+}
+
+/// Select the plural form of the number, and replace `{0}` with the number formatted in the locale.
+fn format_forms(locale: &str, forms: &Forms, number: u64) -> String {
+    let number = number.to_string();
+    let category = PluralOperands::parse(&number).map_or(PluralCategory::Other, |operands| {
+        plural_category(locale, &operands)
+    });
+    let pattern = match forms[category as usize] {
+        "" => forms[5],
+        pattern => pattern,
+    };
+    pattern.replace("{0}", &format_number(locale, &number, NumberStyle::Number))
+}
+
+/// A signed duration to format as a relative time, negative for the past, such as `in 2 days` or `3 minutes ago`.
+///
+/// It's implemented for [`Duration`] in the future, for the `Result` of [`SystemTime::duration_since`](std::time::SystemTime::duration_since),
+/// which is an error for the past, and for `chrono::TimeDelta` and `time::Duration` with the `chrono` and `time` features.
+pub trait SignedDuration {
+    /// The seconds of the duration, negative for the past, rounded to the nearest second.
+    fn whole_seconds(&self) -> i64;
+}
+
+impl SignedDuration for Duration {
+    fn whole_seconds(&self) -> i64 {
+        round_seconds(self).try_into().unwrap_or(i64::MAX)
+    }
+}
+
+impl SignedDuration for Result<Duration, SystemTimeError> {
+    fn whole_seconds(&self) -> i64 {
+        match self {
+            Ok(duration) => duration.whole_seconds(),
+            Err(err) => round_seconds(&err.duration())
+                .try_into()
+                .map_or(i64::MIN, |seconds: i64| -seconds),
+        }
+    }
+}
+
+impl<T: SignedDuration + ?Sized> SignedDuration for &T {
+    fn whole_seconds(&self) -> i64 {
+        (**self).whole_seconds()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SignedDuration for chrono::TimeDelta {
+    fn whole_seconds(&self) -> i64 {
+        let millis = self.num_milliseconds();
+        millis / 1000 + (millis % 1000 * 2 / 1000)
+    }
+}
+
+#[cfg(feature = "time")]
+impl SignedDuration for time::Duration {
+    fn whole_seconds(&self) -> i64 {
+        let nanos = i64::from(self.subsec_nanoseconds());
+        time::Duration::whole_seconds(*self) + nanos * 2 / 1_000_000_000
+    }
+}
+
+/// Round the duration to the nearest second, half up.
+fn round_seconds(duration: &Duration) -> u64 {
+    duration
+        .as_secs()
+        .saturating_add(u64::from(duration.subsec_nanos() >= 500_000_000))
+}
+
+/// Parse the seconds into the sign and the whole seconds.
+fn parse_seconds(seconds: &str) -> Option<(bool, u64)> {
+    let (negative, integer, _) = parse_decimal(seconds)?;
+    Some((negative, integer.parse().unwrap_or(u64::MAX)))
+}
+
+/// Format a relative time in the locale, with the CLDR patterns of the largest unit that keeps the number at least 1,
+/// such as `3 minutes ago` or `in 2 days`, the plural form is selected with the plural rules of the locale.
+///
+/// The seconds are the decimal text of any number type, negative for the past, and the fraction is ignored.
+/// The number is rounded to the nearest whole unit, such as `119` seconds -> `in 2 minutes`.
+/// A month is 30 days and a year is 365 days. The text is returned as-is if it's not a decimal number.
+///
+/// The CLDR data covers `en`, `ar`, `de`, `es`, `fr`, `he`, `it`, `ja`, `ko`, `pt`, `ru` and `zh`,
+/// the other locales are formatted in English.
+///
+/// ```
+/// # use rust_i18n_support::format_relative;
+/// assert_eq!(format_relative("en", "-180"), "3 minutes ago");
+/// assert_eq!(format_relative("en", "172800"), "in 2 days");
+/// assert_eq!(format_relative("es", "-180"), "hace 3 minutos");
+/// ```
+pub fn format_relative(locale: &str, seconds: &str) -> String {
+    let Some((past, seconds)) = parse_seconds(seconds) else {
+        return seconds.to_string();
+    };
+
+    let symbols = relative_symbols(locale);
+    if seconds == 0 {
+        return symbols.now.to_string();
+    }
+    let mut index = UNIT_SECONDS
+        .iter()
+        .rposition(|unit| seconds >= *unit)
+        .unwrap_or(0);
+    let mut count = round_div(seconds, UNIT_SECONDS[index]);
+    // Use the next unit if the number is rounded up to it, such as 59.5 minutes -> 1 hour
+    while let Some(next) = UNIT_SECONDS.get(index + 1) {
+        if count.saturating_mul(UNIT_SECONDS[index]) < *next {
+            break;
+        }
+        index += 1;
+        count = round_div(seconds, *next);
+    }
+    let unit = &symbols.units[index];
+    let forms = if past { &unit.past } else { &unit.future };
+    format_forms(locale, forms, count)
+}
+
+/// Divide and round half up.
+fn round_div(value: u64, divisor: u64) -> u64 {
+    value / divisor + u64::from(value % divisor * 2 >= divisor)
+}
+
+/// Format a duration in the locale, with the days, hours, minutes and seconds that are not zero, such as `1 h 5 min`.
+///
+/// The seconds are the decimal text of any number type, and the fraction is ignored.
+/// The text is returned as-is if it's not a decimal number. The locales are covered like [`format_relative`].
+///
+/// ```
+/// # use rust_i18n_support::format_duration;
+/// assert_eq!(format_duration("en", "3900"), "1 h 5 min");
+/// assert_eq!(format_duration("ja", "3900"), "1時間5分");
+/// ```
+pub fn format_duration(locale: &str, seconds: &str) -> String {
+    let Some((negative, mut seconds)) = parse_seconds(seconds) else {
+        return seconds.to_string();
+    };
+
+    let symbols = relative_symbols(locale);
+    let mut parts = vec![];
+    for (forms, unit) in symbols.duration.iter().zip(DURATION_SECONDS) {
+        let count = seconds / unit;
+        seconds %= unit;
+        if count > 0 {
+            parts.push(format_forms(locale, forms, count));
+        }
+    }
+    if parts.is_empty() {
+        parts.push(format_forms(locale, &symbols.duration[3], 0));
+    }
+
+    let duration = parts.join(symbols.duration_separator);
+    if negative {
+        format!("-{}", duration)
+    } else {
+        duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_relative() {
+        assert_eq!(format_relative("en", "0"), "now");
+        assert_eq!(format_relative("en", "-1"), "1 second ago");
+        assert_eq!(format_relative("en", "30"), "in 30 seconds");
+        assert_eq!(format_relative("en", "-60"), "1 minute ago");
+        assert_eq!(format_relative("en", "59"), "in 59 seconds");
+        assert_eq!(format_relative("en", "89"), "in 1 minute");
+        assert_eq!(format_relative("en", "119"), "in 2 minutes");
+        assert_eq!(format_relative("en", "-179.9"), "3 minutes ago");
+        assert_eq!(format_relative("en", "3569"), "in 59 minutes");
+        assert_eq!(format_relative("en", "3599"), "in 1 hour");
+        assert_eq!(format_relative("en", "-570000"), "1 week ago");
+        assert_eq!(
+            format_relative("en", "18446744073709551615"),
+            "in 584,942,417,355 years"
+        );
+        assert_eq!(format_relative("en", "7200"), "in 2 hours");
+        assert_eq!(format_relative("en-US", "-86400"), "1 day ago");
+        assert_eq!(format_relative("en", "1209600"), "in 2 weeks");
+        assert_eq!(format_relative("en", "-7776000"), "3 months ago");
+        assert_eq!(format_relative("en", "63072000"), "in 2 years");
+        assert_eq!(format_relative("en", "-31536000000"), "1,000 years ago");
+
+        assert_eq!(format_relative("de", "-180"), "vor 3 Minuten");
+        assert_eq!(format_relative("de", "86400"), "in 1 Tag");
+        assert_eq!(format_relative("es", "-180"), "hace 3 minutos");
+        assert_eq!(format_relative("es", "60"), "dentro de 1 minuto");
+        assert_eq!(format_relative("fr", "-7776000"), "il y a 3 mois");
+        assert_eq!(format_relative("it", "-3600"), "1 ora fa");
+        assert_eq!(format_relative("pt-BR", "-2592000"), "há 1 mês");
+        assert_eq!(format_relative("ja", "-180"), "3 分前");
+        assert_eq!(format_relative("zh-CN", "172800"), "2天后");
+        assert_eq!(format_relative("ko", "-180"), "3분 전");
+
+        // The plural forms of Russian
+        assert_eq!(format_relative("ru", "-60"), "1 минуту назад");
+        assert_eq!(format_relative("ru", "-180"), "3 минуты назад");
+        assert_eq!(format_relative("ru", "-300"), "5 минут назад");
+        assert_eq!(format_relative("ru", "-1260"), "21 минуту назад");
+        assert_eq!(format_relative("ru", "157680000"), "через 5 лет");
+
+        // The zero, two, few and many forms of Arabic and Hebrew
+        assert_eq!(format_relative("ar", "-60"), "قبل دقيقة واحدة");
+        assert_eq!(format_relative("ar", "-120"), "قبل دقيقتين");
+        assert_eq!(format_relative("ar", "-180"), "قبل ٣ دقائق");
+        assert_eq!(format_relative("ar", "-660"), "قبل ١١ دقيقة");
+        assert_eq!(format_relative("ar", "86400"), "خلال يوم واحد");
+        assert_eq!(format_relative("ar", "259200"), "خلال ٣ أيام");
+        assert_eq!(format_relative("he", "-7200"), "לפני שעתיים");
+        assert_eq!(format_relative("he", "-18000"), "לפני 5 שעות");
+        assert_eq!(format_relative("he-IL", "172800"), "בעוד יומיים");
+
+        assert_eq!(format_relative("en", "soon"), "soon");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration("en", "0"), "0 s");
+        assert_eq!(format_duration("en", "45"), "45 s");
+        assert_eq!(format_duration("en", "3900"), "1 h 5 min");
+        assert_eq!(format_duration("en", "3900.5"), "1 h 5 min");
+        assert_eq!(format_duration("en", "93784"), "1 d 2 h 3 min 4 s");
+        assert_eq!(format_duration("en", "-60"), "-1 min");
+        assert_eq!(format_duration("de", "3900"), "1 Std. 5 Min.");
+        assert_eq!(format_duration("fr", "90000"), "1 j 1 h");
+        assert_eq!(format_duration("pt", "86400"), "1 dia");
+        assert_eq!(format_duration("pt", "172800"), "2 dias");
+        assert_eq!(format_duration("ru", "3900"), "1 ч 5 мин");
+        assert_eq!(format_duration("zh", "3900"), "1小时5分钟");
+        assert_eq!(format_duration("ko", "3900"), "1시간 5분");
+        assert_eq!(format_duration("ar", "3900"), "١ س ٥ د");
+        assert_eq!(format_duration("he", "3900"), "1 שע׳ 5 דק׳");
+        assert_eq!(format_duration("en", "a while"), "a while");
+    }
+
+    #[test]
+    fn test_signed_duration() {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        assert_eq!(Duration::from_secs(180).whole_seconds(), 180);
+        assert_eq!(Duration::from_millis(1499).whole_seconds(), 1);
+        assert_eq!(Duration::from_millis(1500).whole_seconds(), 2);
+        assert_eq!(Duration::MAX.whole_seconds(), i64::MAX);
+
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+        assert_eq!(UNIX_EPOCH.duration_since(now).whole_seconds(), -1000);
+        assert_eq!(now.duration_since(UNIX_EPOCH).whole_seconds(), 1000);
+        assert!(SystemTime::now().duration_since(UNIX_EPOCH).whole_seconds() > 0);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_signed_duration_chrono() {
+        assert_eq!(chrono::TimeDelta::seconds(-180).whole_seconds(), -180);
+        assert_eq!(chrono::TimeDelta::milliseconds(-1500).whole_seconds(), -2);
+        assert_eq!(chrono::TimeDelta::milliseconds(1499).whole_seconds(), 1);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_signed_duration_time() {
+        assert_eq!(
+            SignedDuration::whole_seconds(&time::Duration::seconds(-180)),
+            -180
+        );
+        assert_eq!(
+            SignedDuration::whole_seconds(&time::Duration::milliseconds(-1500)),
+            -2
+        );
+        assert_eq!(
+            SignedDuration::whole_seconds(&time::Duration::milliseconds(1499)),
+            1
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use crate::currency::is_currency_code;
+use crate::{
+    format_bytes, format_currency, format_duration, format_list, format_number_with_precision,
+    format_relative, format_unit, CurrencyDisplay, ListStyle, NumberStyle, Unit,
+};
+
+/// The date and time styles, such as `%{when:date_short}`, formatted with the `datetime` feature.
+pub const DATETIME_STYLES: &[&str] = &[
+    "date_short",
+    "date_medium",
+    "date_long",
+    "date_full",
+    "time_short",
+    "time_medium",
+    "datetime_short",
+    "datetime_medium",
+    "datetime_long",
+    "datetime_full",
+];
+
+/// The style of the values, such as `number`, `number:.2`, `currency:USD`, `unit:kilometer` or `list`.
+enum ValueStyle {
+    /// The number style with the fraction digits of the `:.2` precision if any.
+    Number(NumberStyle, Option<usize>),
+    Currency(String, CurrencyDisplay),
+    Unit(Unit),
+    Bytes,
+    Relative,
+    Duration,
+    List(ListStyle),
+}
+
+impl ValueStyle {
+    fn parse(style: &str) -> Option<Self> {
+        if let Some(style) = ListStyle::parse(style) {
+            return Some(Self::List(style));
+        }
+        let (name, arg) = style.split_once(':').unwrap_or((style, ""));
+        if let Some(style) = NumberStyle::parse(name) {
+            if arg.is_empty() {
+                return Some(Self::Number(style, None));
+            }
+            let digits = arg.strip_prefix('.')?;
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            return digits
+                .parse()
+                .ok()
+                .map(|digits| Self::Number(style, Some(digits)));
+        }
+        match name {
+            "currency" if is_currency_code(arg) => {
+                Some(Self::Currency(arg.to_string(), CurrencyDisplay::Symbol))
+            }
+            "currency_code" if is_currency_code(arg) => {
+                Some(Self::Currency(arg.to_string(), CurrencyDisplay::Code))
+            }
+            "unit" => Unit::parse(arg).map(Self::Unit),
+            "bytes" if arg.is_empty() => Some(Self::Bytes),
+            "relative" if arg.is_empty() => Some(Self::Relative),
+            "duration" if arg.is_empty() => Some(Self::Duration),
+            _ => None,
+        }
+    }
+}
+
+/// Check if the name is a locale-aware style of the values, such as `number`, `currency:USD` or `date_short`,
+/// rather than a [`std::fmt`] specifier.
+pub fn is_value_style(style: &str) -> bool {
+    ValueStyle::parse(style).is_some() || DATETIME_STYLES.contains(&style)
+}
+
+/// Format the value in the locale with the style, returns `None` if the style is not a locale-aware style,
+/// or it's a date and time style without the `datetime` feature.
+///
+/// ```
+/// # use rust_i18n_support::format_value;
+/// assert_eq!(format_value("de", "1234.5", "number").unwrap(), "1.234,5");
+/// assert_eq!(format_value("en", "1234.5", "currency:USD").unwrap(), "$1,234.50");
+/// assert_eq!(format_value("de", "1234.5", ""), None);
+/// ```
+pub fn format_value(locale: &str, value: &str, style: &str) -> Option<String> {
+    match ValueStyle::parse(style) {
+        Some(ValueStyle::Number(style, precision)) => {
+            return Some(format_number_with_precision(
+                locale, value, style, precision,
+            ))
+        }
+        Some(ValueStyle::Currency(code, display)) => {
+            return Some(format_currency(locale, value, &code, display))
+        }
+        Some(ValueStyle::Unit(unit)) => return Some(format_unit(locale, value, unit)),
+        Some(ValueStyle::Bytes) => return Some(format_bytes(locale, value)),
+        Some(ValueStyle::Relative) => return Some(format_relative(locale, value)),
+        Some(ValueStyle::Duration) => return Some(format_duration(locale, value)),
+        Some(ValueStyle::List(style)) => return Some(format_list(locale, &[value], style)),
+        None => {}
+    }
+    #[cfg(feature = "datetime")]
+    if let Some(style) = crate::DateTimeStyle::parse(style) {
+        return Some(crate::format_datetime(locale, value, style));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_value_style() {
+        assert!(is_value_style("number"));
+        assert!(is_value_style("percent"));
+        assert!(is_value_style("number:.2"));
+        assert!(is_value_style("percent:.0"));
+        assert!(is_value_style("currency:USD"));
+        assert!(is_value_style("currency_code:eur"));
+        assert!(is_value_style("unit:kilometer"));
+        assert!(is_value_style("bytes"));
+        assert!(is_value_style("relative"));
+        assert!(is_value_style("duration"));
+        assert!(is_value_style("list"));
+        assert!(is_value_style("list_or"));
+        assert!(is_value_style("date_short"));
+        assert!(is_value_style("datetime_full"));
+        assert!(!is_value_style(":>10"));
+        assert!(!is_value_style("date"));
+        assert!(!is_value_style("number:2"));
+        assert!(!is_value_style("number:."));
+        assert!(!is_value_style("number:.x"));
+        assert!(!is_value_style("currency"));
+        assert!(!is_value_style("currency:DOLLAR"));
+        assert!(!is_value_style("unit:furlong"));
+    }
+
+    #[test]
+    fn test_format_value() {
+        let format = |locale, value, style| format_value(locale, value, style);
+        assert_eq!(format("de", "0.5", "percent").unwrap(), "50\u{A0}%");
+        assert_eq!(format("de", "1234.5", "number:.2").unwrap(), "1.234,50");
+        assert_eq!(format("en", "0.12345", "percent:.1").unwrap(), "12.3%");
+        assert_eq!(
+            format("de", "1234.5", "currency:EUR").unwrap(),
+            "1.234,50\u{A0}€"
+        );
+        assert_eq!(
+            format("en", "1234.5", "currency_code:EUR").unwrap(),
+            "EUR\u{A0}1,234.50"
+        );
+        assert_eq!(
+            format("en", "12.5", "unit:kilometer").unwrap(),
+            "12.5\u{A0}km"
+        );
+        assert_eq!(format("en", "1536000", "bytes").unwrap(), "1.5\u{A0}MB");
+        assert_eq!(format("es", "-180", "relative").unwrap(), "hace 3 minutos");
+        assert_eq!(format("en", "3900", "duration").unwrap(), "1 h 5 min");
+        // A text value is a list of one item
+        assert_eq!(format("en", "A", "list_or").unwrap(), "A");
+        assert_eq!(format("en", "12", ":>10"), None);
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_datetime_styles() {
+        for style in DATETIME_STYLES {
+            assert!(crate::DateTimeStyle::parse(style).is_some(), "{}", style);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::locale::locale_entry;
+use crate::number::{parse_decimal, percent_pattern, round_decimal};
+use crate::{format_number, NumberStyle};
+
+/// The measurement unit of [`format_unit`], selected by `%{name:unit:kilometer}` in a translation
+/// or `name = value : {unit:kilometer}` in `t!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    /// `byte`, such as `512 B`.
+    Byte,
+    /// `kilobyte`, such as `1.5 kB`.
+    Kilobyte,
+    /// `megabyte`, such as `1.5 MB`.
+    Megabyte,
+    /// `gigabyte`, such as `1.5 GB`.
+    Gigabyte,
+    /// `terabyte`, such as `1.5 TB`.
+    Terabyte,
+    /// `meter`, such as `100 m`.
+    Meter,
+    /// `kilometer`, such as `12.5 km`.
+    Kilometer,
+    /// `kilogram`, such as `2 kg`.
+    Kilogram,
+    /// `percent`, the number that is already a percentage, such as `12.5%` of `12.5`.
+    Percent,
+}
+
+impl Unit {
+    /// Parse the CLDR unit name, such as `kilometer`, returns `None` if it's not a supported unit.
+    pub fn parse(unit: &str) -> Option<Self> {
+        Some(match unit {
+            "byte" => Self::Byte,
+            "kilobyte" => Self::Kilobyte,
+            "megabyte" => Self::Megabyte,
+            "gigabyte" => Self::Gigabyte,
+            "terabyte" => Self::Terabyte,
+            "meter" => Self::Meter,
+            "kilometer" => Self::Kilometer,
+            "kilogram" => Self::Kilogram,
+            "percent" => Self::Percent,
+            _ => return None,
+        })
+    }
+}
+
+/// The unit symbols from byte to terabyte, then meter, kilometer and kilogram.
+type UnitSymbols = [&'static str; 8];
+
+const LATIN: UnitSymbols = ["B", "kB", "MB", "GB", "TB", "m", "km", "kg"];
+
+/// The unit symbols of the locales, the other locales use [`LATIN`].
+const LOCALE_SYMBOLS: &[(&str, UnitSymbols)] = &[
+    ("fr", ["o", "ko", "Mo", "Go", "To", "m", "km", "kg"]),
+    ("ru", ["Б", "кБ", "МБ", "ГБ", "ТБ", "м", "км", "кг"]),
+    ("uk", ["Б", "кБ", "МБ", "ГБ", "ТБ", "м", "км", "кг"]),
+];
+
+const BYTE_UNITS: [Unit; 5] = [
+    Unit::Byte,
+    Unit::Kilobyte,
+    Unit::Megabyte,
+    Unit::Gigabyte,
+    Unit::Terabyte,
+];
+
+fn unit_symbol(locale: &str, unit: Unit) -> &'static str {
+    let symbols = locale_entry(LOCALE_SYMBOLS, locale).unwrap_or(&LATIN);
+    match unit {
+        Unit::Byte => symbols[0],
+        Unit::Kilobyte => symbols[1],
+        Unit::Megabyte => symbols[2],
+        Unit::Gigabyte => symbols[3],
+        Unit::Terabyte => symbols[4],
+        Unit::Meter => symbols[5],
+        Unit::Kilometer => symbols[6],
+        Unit::Kilogram => symbols[7],
+        Unit::Percent => "%",
+    }
+}
+
+/// Format a measurement in the locale, with the separators of the locale and the symbol of the unit.
+///
+/// The value is the decimal text of any number type, such as `12.5`, so the precision is kept as-is.
+/// The text is returned as-is if it's not a decimal number.
+///
+/// ```
+/// # use rust_i18n_support::{format_unit, Unit};
+/// assert_eq!(format_unit("en", "1234.5", Unit::Kilometer), "1,234.5\u{A0}km");
+/// assert_eq!(format_unit("de", "12.5", Unit::Percent), "12,5\u{A0}%");
+/// ```
+pub fn format_unit(locale: &str, value: &str, unit: Unit) -> String {
+    if parse_decimal(value).is_none() {
+        return value.to_string();
+    }
+    let number = format_number(locale, value, NumberStyle::Number);
+    match unit {
+        Unit::Percent => percent_pattern(locale).replace("{}", &number),
+        _ => format!("{}\u{A0}{}", number, unit_symbol(locale, unit)),
+    }
+}
+
+/// Format a number of bytes in the locale, in the largest unit from byte to terabyte that keeps the number at least 1,
+/// with 1 kilobyte of 1000 bytes, rounded to 1 fraction digit.
+///
+/// ```
+/// # use rust_i18n_support::format_bytes;
+/// assert_eq!(format_bytes("en", "512"), "512\u{A0}B");
+/// assert_eq!(format_bytes("en", "1536000"), "1.5\u{A0}MB");
+/// assert_eq!(format_bytes("fr", "1536000"), "1,5\u{A0}Mo");
+/// ```
+pub fn format_bytes(locale: &str, bytes: &str) -> String {
+    let Some((negative, integer, _)) = parse_decimal(bytes) else {
+        return bytes.to_string();
+    };
+    let integer = integer.trim_start_matches('0');
+    let integer = if integer.is_empty() { "0" } else { integer };
+
+    let scale = ((integer.len() - 1) / 3).min(BYTE_UNITS.len() - 1);
+    let (integer, fraction) = integer.split_at(integer.len() - scale * 3);
+    let (mut integer, fraction) = round_decimal(integer, fraction, usize::from(scale > 0));
+    let mut scale = scale;
+    // Rounded up to the next unit, such as `999.96 kB` -> `1 MB`
+    if integer.len() > 3 && scale + 1 < BYTE_UNITS.len() {
+        scale += 1;
+        integer = format!("{}.{}", &integer[..1], &integer[1..2]);
+    } else if !fraction.is_empty() {
+        integer = format!("{}.{}", integer, fraction);
+    }
+    let number = integer.strip_suffix(".0").unwrap_or(&integer);
+    let number = if negative {
+        format!("-{}", number)
+    } else {
+        number.to_string()
+    };
+    format_unit(locale, &number, BYTE_UNITS[scale])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_unit() {
+        assert_eq!(format_unit("en", "12.5", Unit::Kilometer), "12.5\u{A0}km");
+        assert_eq!(
+            format_unit("de", "1234.5", Unit::Kilometer),
+            "1.234,5\u{A0}km"
+        );
+        assert_eq!(format_unit("ru", "2", Unit::Kilogram), "2\u{A0}кг");
+        assert_eq!(format_unit("fr", "512", Unit::Byte), "512\u{A0}o");
+        assert_eq!(format_unit("en", "100", Unit::Meter), "100\u{A0}m");
+        assert_eq!(format_unit("en", "12.5", Unit::Percent), "12.5%");
+        assert_eq!(format_unit("fr", "12.5", Unit::Percent), "12,5\u{202F}%");
+        assert_eq!(format_unit("en", "far", Unit::Kilometer), "far");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes("en", "0"), "0\u{A0}B");
+        assert_eq!(format_bytes("en", "999"), "999\u{A0}B");
+        assert_eq!(format_bytes("en", "1000"), "1\u{A0}kB");
+        assert_eq!(format_bytes("en", "1536"), "1.5\u{A0}kB");
+        assert_eq!(format_bytes("en", "1536000"), "1.5\u{A0}MB");
+        assert_eq!(format_bytes("en", "999960"), "1\u{A0}MB");
+        assert_eq!(format_bytes("en", "5000000000"), "5\u{A0}GB");
+        assert_eq!(format_bytes("en", "1234567890123456"), "1,234.6\u{A0}TB");
+        assert_eq!(format_bytes("en", "1536.7"), "1.5\u{A0}kB");
+        assert_eq!(format_bytes("de", "1536000"), "1,5\u{A0}MB");
+        assert_eq!(format_bytes("ru", "1536000"), "1,5\u{A0}МБ");
+        assert_eq!(format_bytes("en", "-2048"), "-2\u{A0}kB");
+        assert_eq!(format_bytes("en", "big"), "big");
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(Unit::parse("kilometer"), Some(Unit::Kilometer));
+        assert_eq!(Unit::parse("percent"), Some(Unit::Percent));
+        assert_eq!(Unit::parse("km"), None);
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use rust_i18n_support::{CurrencyDisplay, ListStyle, SignedDuration, Unit};
+
+use crate::locale;
+
+/// Format an amount of money in the current locale, with the symbol of the currency, such as `$1,234.50`.
+///
+/// The amount is rounded to the minor unit digits of the ISO 4217 currency, such as 2 of `USD` and 0 of `JPY`.
+/// It's formatted like `%{amount:currency:USD}` in a translation, use [`crate::with_locale`] to format in another locale.
+///
+/// ```
+/// rust_i18n::set_locale("de");
+/// assert_eq!(rust_i18n::format_currency(1234.5, "EUR"), "1.234,50\u{A0}€");
+/// # rust_i18n::set_locale("en");
+/// ```
+pub fn format_currency(amount: impl Display, currency: &str) -> String {
+    rust_i18n_support::format_currency(
+        &locale(),
+        &amount.to_string(),
+        currency,
+        CurrencyDisplay::Symbol,
+    )
+}
+
+/// Format an amount of money in the current locale, with the ISO 4217 code of the currency, such as `USD 1,234.50`.
+///
+/// It's formatted like `%{amount:currency_code:USD}` in a translation.
+pub fn format_currency_code(amount: impl Display, currency: &str) -> String {
+    rust_i18n_support::format_currency(
+        &locale(),
+        &amount.to_string(),
+        currency,
+        CurrencyDisplay::Code,
+    )
+}
+
+/// Format a measurement in the current locale, with the symbol of the unit, such as `12.5 km`.
+///
+/// It's formatted like `%{distance:unit:kilometer}` in a translation.
+///
+/// ```
+/// use rust_i18n::Unit;
+///
+/// rust_i18n::with_locale("ru", || {
+///     assert_eq!(rust_i18n::format_unit(12.5, Unit::Kilometer), "12,5\u{A0}км");
+/// });
+/// ```
+pub fn format_unit(value: impl Display, unit: Unit) -> String {
+    rust_i18n_support::format_unit(&locale(), &value.to_string(), unit)
+}
+
+/// Format a number of bytes in the current locale, in the largest unit that keeps the number at least 1, such as `1.5 MB`.
+///
+/// It's formatted like `%{size:bytes}` in a translation.
+pub fn format_bytes(bytes: impl Display) -> String {
+    rust_i18n_support::format_bytes(&locale(), &bytes.to_string())
+}
+
+/// Format a relative time in the locale, such as `3 minutes ago` or `in 2 days`,
+/// in the largest unit that keeps the number at least 1, rounded to the nearest whole unit, with the plural rules of the locale.
+///
+/// The duration is a [`SignedDuration`], negative for the past, such as a [`Duration`] in the future,
+/// `then.duration_since(now)` of two [`SystemTime`](std::time::SystemTime)s, or `chrono::TimeDelta` and `time::Duration`
+/// with the `chrono` and `time` features. Pass `&rust_i18n::locale()` to format in the current locale.
+/// It's formatted like `%{elapsed:relative}` in a translation, with the seconds as the value.
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let then = UNIX_EPOCH;
+/// let now = UNIX_EPOCH + Duration::from_secs(180);
+/// assert_eq!(rust_i18n::format_relative(then.duration_since(now), "en"), "3 minutes ago");
+/// assert_eq!(rust_i18n::format_relative(Duration::from_secs(172800), "en"), "in 2 days");
+/// assert_eq!(rust_i18n::format_relative(then.duration_since(now), "es"), "hace 3 minutos");
+/// ```
+pub fn format_relative(duration: impl SignedDuration, locale: &str) -> String {
+    rust_i18n_support::format_relative(locale, &duration.whole_seconds().to_string())
+}
+
+/// Format a duration in the locale, with the days, hours, minutes and seconds that are not zero, such as `1 h 5 min`.
+///
+/// The duration is rounded to the nearest second, such as `1.5` seconds -> `2 s`.
+/// It's formatted like `%{elapsed:duration}` in a translation, with the seconds as the value.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// assert_eq!(rust_i18n::format_duration(Duration::from_secs(3900), "en"), "1 h 5 min");
+/// assert_eq!(rust_i18n::format_duration(Duration::from_secs(3900), "de"), "1 Std. 5 Min.");
+/// assert_eq!(rust_i18n::format_duration(Duration::from_millis(1500), "en"), "2 s");
+/// ```
+pub fn format_duration(duration: Duration, locale: &str) -> String {
+    rust_i18n_support::format_duration(locale, &duration.whole_seconds().to_string())
+}
+
+/// Join the items into a list in the current locale, such as `A, B, and C`, `A、B和C` or `A, B y C`.
+///
+/// It's formatted like `name = items : {list}` in [`t!`](crate::t!), with `list`, `list_or` or `list_unit` as the style.
+///
+/// ```
+/// use rust_i18n::ListStyle;
+///
+/// rust_i18n::with_locale("zh-CN", || {
+///     assert_eq!(rust_i18n::format_list(&["A", "B", "C"], ListStyle::And), "A、B和C");
+/// });
+/// ```
+pub fn format_list<S: AsRef<str>>(items: &[S], style: ListStyle) -> String {
+    rust_i18n_support::format_list(&locale(), items, style)
+}
@@ -1,6 +1,9 @@
 #![doc = include_str!("../README.md")]
 
+use std::borrow::Cow;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 use once_cell::sync::Lazy;
 
@@ -8,28 +11,107 @@ use once_cell::sync::Lazy;
 pub use once_cell;
 #[doc(hidden)]
 pub use rust_i18n_macro::{_minify_key, _tr, i18n};
+#[doc(hidden)]
+pub use rust_i18n_support::{_ListItems, _format_icu_translation};
 pub use rust_i18n_support::{
-    AtomicStr, Backend, BackendExt, CowStr, MinifyKey, SimpleBackend, DEFAULT_MINIFY_KEY,
-    DEFAULT_MINIFY_KEY_LEN, DEFAULT_MINIFY_KEY_PREFIX, DEFAULT_MINIFY_KEY_THRESH,
+    canonicalize_locale, env_locales, find_links, format_icu_message, format_number,
+    negotiate_locale, ordinal_category, parse_accept_language, plural_category, posix_to_bcp47,
+    replace_links, AtomicStr, Backend, BackendExt, CowStr, IcuError, IcuMessage, Link, ListStyle,
+    MinifyKey, NumberStyle, PluralCategory, PluralOperands, PluralRuleType, Resolved,
+    SignedDuration, SimpleBackend, Unit, UnsupportedLocale, DEFAULT_MINIFY_KEY,
+    DEFAULT_MINIFY_KEY_LEN, DEFAULT_MINIFY_KEY_PREFIX, DEFAULT_MINIFY_KEY_THRESH, MAX_LINK_DEPTH,
+};
+#[cfg(feature = "datetime")]
+pub use rust_i18n_support::{format_datetime, DateTimeStyle, DateTimeValue};
+use rust_i18n_support::{format_value, parse_segments, Segment};
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing;
+
+mod format;
+mod locale_change;
+mod missing;
+mod scoped_locale;
+pub use format::{
+    format_bytes, format_currency, format_currency_code, format_duration, format_list,
+    format_relative, format_unit,
 };
+pub use locale_change::{locale_generation, on_locale_change, LocaleChangeGuard};
+#[doc(hidden)]
+pub use missing::{_missing_key, _report_missing};
+pub use missing::{
+    clear_missing_translations, collect_missing, missing_translations, missing_translations_yaml,
+    set_missing_handler, MissingHandler, MissingTranslation,
+};
+pub use scoped_locale::{set_thread_locale, with_locale, with_locale_async, WithLocale};
 
 static CURRENT_LOCALE: Lazy<AtomicStr> = Lazy::new(|| AtomicStr::from("en"));
+/// Whether the locale is set, so the `locale_from_env` option of `i18n!` does not override it.
+static LOCALE_SET: AtomicBool = AtomicBool::new(false);
 
 /// Set current locale
+///
+/// This is the global locale of all threads, use [`with_locale`] or [`set_thread_locale`] to override it in a thread.
+///
+/// The locale is canonicalized with [`canonicalize_locale`], such as `en_us` -> `en-US`.
+/// If the locale is changed, the callbacks of [`on_locale_change`] are called.
 pub fn set_locale(locale: &str) {
-    CURRENT_LOCALE.replace(locale);
+    LOCALE_SET.store(true, Ordering::Release);
+    swap_locale(locale);
+}
+
+/// Set the initial locale, unless the locale is already set by [`set_locale`].
+///
+/// It's called with the locale of the environment variables when the `t!` of `i18n!("...", locale_from_env = true)` is first used.
+#[doc(hidden)]
+pub fn _init_locale(locale: &str) {
+    if !LOCALE_SET.swap(true, Ordering::AcqRel) {
+        swap_locale(locale);
+    }
+}
+
+fn swap_locale(locale: &str) {
+    let locale = canonicalize_locale(locale);
+    let previous = CURRENT_LOCALE.swap(locale.as_ref());
+    if *previous != *locale {
+        locale_change::notify(&locale);
+    }
+}
+
+/// The current locale, from the thread locale or the global locale.
+enum CurrentLocale<G> {
+    Thread(Arc<str>),
+    Global(G),
+}
+
+impl<G: Deref<Target = str>> Deref for CurrentLocale<G> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Thread(locale) => locale,
+            Self::Global(locale) => locale,
+        }
+    }
 }
 
 /// Get current locale
+///
+/// Returns the locale of the current thread if it's set by [`with_locale`] or [`set_thread_locale`],
+/// otherwise the global locale of [`set_locale`].
 pub fn locale() -> impl Deref<Target = str> {
-    CURRENT_LOCALE.as_str()
+    match scoped_locale::thread_locale() {
+        Some(locale) => CurrentLocale::Thread(locale),
+        None => CurrentLocale::Global(CURRENT_LOCALE.as_str()),
+    }
 }
 
 /// Replace patterns and return a new string.
 ///
 /// # Arguments
 ///
-/// * `input` - The input string, containing patterns like `%{name}`.
+/// * `input` - The input string, containing patterns like `%{name}`, use `%%{name}` for a literal `%{name}`.
+///   The format of `%{name:number}` is ignored, use [`t!`] to format the values in the locale.
 /// * `patterns` - The patterns to replace.
 /// * `values` - The values to replace.
 ///
@@ -42,53 +124,116 @@ pub fn locale() -> impl Deref<Target = str> {
 /// let values = &["world".to_string()];
 /// let output = replace_patterns(input, patterns, values);
 /// assert_eq!(output, "Hello, world!");
+///
+/// let output = replace_patterns("Use %%{name} for the name", patterns, values);
+/// assert_eq!(output, "Use %{name} for the name");
 /// ```
 pub fn replace_patterns(input: &str, patterns: &[&str], values: &[String]) -> String {
-    let input_bytes = input.as_bytes();
-    let mut pattern_pos = smallvec::SmallVec::<[usize; 64]>::new();
-    let mut stage = 0;
-    for (i, &b) in input_bytes.iter().enumerate() {
-        match (stage, b) {
-            (1, b'{') => {
-                stage = 2;
-                pattern_pos.push(i);
-            }
-            (2, b'}') => {
-                stage = 0;
-                pattern_pos.push(i);
-            }
-            (_, b'%') => {
-                stage = 1;
-            }
-            _ => {}
-        }
+    replace_patterns_with(None, input, patterns, values, &[], &[])
+}
+
+/// Replace patterns, and format the values in the locale with the `%{name:number}` or `%{name:date_short}` format of the pattern,
+/// or the format of the value in `formats`, such as `"number"` for `t!("...", name = value : {number})`.
+///
+/// The `lists` are the indexes and the items of the `name = items : {list}` arguments, their values are ignored.
+#[doc(hidden)]
+pub fn _replace_patterns_in(
+    locale: &str,
+    input: &str,
+    patterns: &[&str],
+    values: &[String],
+    formats: &[&str],
+    lists: &[(usize, Vec<String>)],
+) -> String {
+    replace_patterns_with(Some(locale), input, patterns, values, formats, lists)
+}
+
+/// Format the values in the locale with the formats, such as `"number"` or `"date_short"`, before the ICU messages are formatted.
+#[doc(hidden)]
+pub fn _format_values<'a>(
+    locale: &str,
+    values: &'a [String],
+    formats: &[&str],
+    lists: &[(usize, Vec<String>)],
+) -> Cow<'a, [String]> {
+    if lists.is_empty() && formats.iter().all(|format| format.is_empty()) {
+        return Cow::Borrowed(values);
     }
-    let mut output: Vec<u8> = Vec::with_capacity(input_bytes.len() + 128);
-    let mut prev_end = 0;
-    let pattern_values = patterns.iter().zip(values.iter());
-    for pos in pattern_pos.chunks_exact(2) {
-        let start = pos[0];
-        let end = pos[1];
-        let key = &input_bytes[start + 1..end];
-        if prev_end < start {
-            let prev_chunk = &input_bytes[prev_end..start - 1];
-            output.extend_from_slice(prev_chunk);
-        }
-        if let Some((_, v)) = pattern_values
-            .clone()
-            .find(|(&pattern, _)| pattern.as_bytes() == key)
-        {
-            output.extend_from_slice(v.as_bytes());
-        } else {
-            output.extend_from_slice(&input_bytes[start - 1..end + 1]);
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            format_arg(locale, index, value, formats.get(index).copied(), lists)
+                .unwrap_or_else(|| value.clone())
+        })
+        .collect()
+}
+
+/// Format the value of the argument at the index with the style, returns `None` if it's not a locale-aware style.
+///
+/// The lists are always formatted as lists, in the `list` style if the style is not a list style, such as `%{users:number}`.
+fn format_arg(
+    locale: &str,
+    index: usize,
+    value: &str,
+    style: Option<&str>,
+    lists: &[(usize, Vec<String>)],
+) -> Option<String> {
+    if let Some((_, items)) = lists.iter().find(|(i, _)| *i == index) {
+        let style = style.and_then(ListStyle::parse).unwrap_or(ListStyle::And);
+        return Some(rust_i18n_support::format_list(locale, items, style));
+    }
+    format_value(locale, value, style?)
+}
+
+fn replace_patterns_with(
+    locale: Option<&str>,
+    input: &str,
+    patterns: &[&str],
+    values: &[String],
+    formats: &[&str],
+    lists: &[(usize, Vec<String>)],
+) -> String {
+    let mut output = String::with_capacity(input.len() + 128);
+    for segment in parse_segments(input) {
+        match segment {
+            Segment::Text(text) => output.push_str(text),
+            Segment::Placeholder(placeholder) => {
+                let (name, format) = match placeholder.split_once(':') {
+                    Some((name, format)) => (name, Some(format)),
+                    None => (placeholder, None),
+                };
+                match patterns.iter().position(|&p| p == name) {
+                    Some(index) if index < values.len() => {
+                        let value = &values[index];
+                        let style = format.or(formats.get(index).copied());
+                        let formatted = locale
+                            .and_then(|locale| format_arg(locale, index, value, style, lists));
+                        output.push_str(formatted.as_deref().unwrap_or(value));
+                    }
+                    _ => {
+                        output.push_str("%{");
+                        output.push_str(placeholder);
+                        output.push('}');
+                    }
+                }
+            }
         }
-        prev_end = end + 1;
     }
-    if prev_end < input_bytes.len() {
-        let remaining = &input_bytes[prev_end..];
-        output.extend_from_slice(remaining);
+    output
+}
+
+/// Resolve the `%%{` escapes of a message without arguments.
+///
+/// The input is returned as-is if there is no escape.
+#[doc(hidden)]
+pub fn _unescape_patterns<'a, T: Into<Cow<'a, str>>>(input: T) -> Cow<'a, str> {
+    let input = input.into();
+    if input.contains("%%{") {
+        Cow::from(replace_patterns(&input, &[], &[]))
+    } else {
+        input
     }
-    unsafe { String::from_utf8_unchecked(output) }
 }
 
 /// Get I18n text
@@ -104,10 +249,17 @@ pub fn replace_patterns(input: &str, patterns: &[&str], values: &[String]) -> St
 ///   - Dynamic messages are also supported, such as `t!(format!("Hello, {}!", name))`.
 ///     However, if `minify_key` is enabled, the entire message will be hashed and used as a key for every lookup, which may consume more CPU cycles.
 /// * `locale` - The locale to use. If not specified, the current locale will be used.
+/// * `default` - The string literal to use if the key is missing in the locale and all its fallback locales, instead of the key itself.
+///   - The placeholders in it are replaced with the `args` too, and `cargo i18n` uses it as the initial value of the key.
+///   - Other values, such as `default = value`, fill the `%{default}` placeholder.
+/// * `strict` - Set `strict = false` to skip the compile-time key check of the `strict` option of [`i18n!`] for this call.
+///   - Without the `strict` option, it fills the `%{strict}` placeholder like the other arguments.
 /// * `args` - The arguments to be replaced in the translated text.
 ///    - These should be passed in the format `key = value` or `key => value`.
 ///    - Alternatively, you can specify the value format using the `key = value : {:format_specifier}` syntax.
 ///      For example, `key = value : {:08}` will format the value as a zero-padded string with a length of 8.
+///    - The `count` argument also selects the CLDR plural form of the translation, see below.
+///    - The `ordinal` argument selects the CLDR ordinal form of the translation, see below.
 ///
 /// # Example
 ///
@@ -137,6 +289,18 @@ pub fn replace_patterns(input: &str, patterns: &[&str], values: &[String]) -> St
 /// // With locale and variables
 /// t!("messages.hello", locale = "de", name = "Jason");
 /// // messages.hello: "Hallo, %{name}" => "Hallo, Jason"
+///
+/// // With a default text for the missing key
+/// t!("checkout.title", default = "Checkout, %{name}", name = "Jason");
+/// // checkout.title is missing => "Checkout, Jason"
+///
+/// // With plural forms, looks up `inbox.messages.{zero,one,two,few,many,other}`
+/// t!("inbox.messages", count = 3);
+/// // inbox.messages.other: "You have %{count} messages" => "You have 3 messages"
+///
+/// // With ordinal forms, looks up `rank.ordinal.{zero,one,two,few,many,other}`
+/// t!("rank", ordinal = 2);
+/// // rank.ordinal.two: "%{ordinal}nd" => "2nd"
 /// # }
 /// ```
 #[macro_export]
@@ -182,12 +346,12 @@ macro_rules! tkv {
 /// Get available locales
 ///
 /// ```no_run
-/// #[macro_use] extern crate rust_i18n;
-/// # pub fn _rust_i18n_available_locales() -> Vec<&'static str> { todo!() }
+/// rust_i18n::i18n!("locales");
+///
 /// # fn main() {
 /// rust_i18n::available_locales!();
-/// # }
 /// // => ["en", "zh-CN"]
+/// # }
 /// ```
 #[macro_export(local_inner_macros)]
 #[allow(clippy::crate_in_macro_def)]
@@ -197,6 +361,148 @@ macro_rules! available_locales {
     };
 }
 
+/// Pick the best available locale for the requested locales, in order of preference.
+///
+/// The requested locales are matched with the RFC 4647 lookup, for example `"zh-Hant-TW"` falls back to
+/// `"zh-Hant"` and then `"zh"`. If none matches, the first `fallback` locale of `i18n!` (or the default locale) is returned.
+///
+/// Use [`parse_accept_language`] to get the requested locales from an `Accept-Language` header,
+/// and [`negotiate()`] to pick among other locales.
+///
+/// ```no_run
+/// rust_i18n::i18n!("locales", fallback = "en");
+///
+/// # fn main() {
+/// rust_i18n::negotiate!(&["de-AT", "en"]);
+/// // => "de"
+/// let requested = rust_i18n::parse_accept_language("zh-Hant-TW, zh;q=0.9, en;q=0.8");
+/// rust_i18n::negotiate!(&requested);
+/// // => "zh"
+/// # }
+/// ```
+#[macro_export(local_inner_macros)]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! negotiate {
+    ($requested:expr) => {
+        crate::_rust_i18n_negotiate($requested)
+    };
+}
+
+/// Pick the best match of the requested locales among the available locales, in order of preference.
+///
+/// The requested locales are matched with the RFC 4647 lookup like [`negotiate!`], for example `"zh-Hant-TW"`
+/// falls back to `"zh-Hant"` and then `"zh"`, and returns `None` if none matches.
+/// Use [`available_locales!`] for the locales of the translations, or another list such as the locales of a user account.
+///
+/// ```
+/// let available = ["de", "en", "zh-Hant"];
+/// assert_eq!(rust_i18n::negotiate(&["de-AT", "en"], &available), Some("de"));
+/// assert_eq!(rust_i18n::negotiate(&["zh-Hant-TW"], &available), Some("zh-Hant"));
+/// assert_eq!(rust_i18n::negotiate(&["fr"], &available), None);
+///
+/// let requested = rust_i18n::parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8");
+/// assert_eq!(rust_i18n::negotiate(&requested, &available), Some("en"));
+/// ```
+pub fn negotiate<'a, S: AsRef<str>>(requested: &[S], available: &[&'a str]) -> Option<&'a str> {
+    negotiate_locale(requested, available, |locale| {
+        locale
+            .rfind('-')
+            .map(|n| locale[..n].trim_end_matches("-x"))
+    })
+}
+
+/// Detect the locale of the user from the `LC_ALL`, `LC_MESSAGES`, `LANG` and `LANGUAGE` environment variables.
+///
+/// The POSIX locales such as `zh_CN.UTF-8` are converted to BCP 47 and negotiated against the available locales
+/// like [`negotiate!`], returns `None` if none matches, or the locale is `C` or `POSIX`.
+///
+/// ```no_run
+/// rust_i18n::i18n!("locales");
+///
+/// # fn main() {
+/// // LANG=zh_CN.UTF-8
+/// rust_i18n::detect_locale!();
+/// // => Some("zh-CN")
+/// # }
+/// ```
+#[macro_export(local_inner_macros)]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! detect_locale {
+    () => {
+        crate::_rust_i18n_detect_locale()
+    };
+}
+
+/// Set the current locale to the result of [`detect_locale!`], if any, and return it.
+///
+/// Use the `locale_from_env = true` option of `i18n!` to do it when the first [`t!`] without `locale` is called,
+/// unless the locale is already set with [`set_locale`].
+///
+/// ```no_run
+/// rust_i18n::i18n!("locales");
+///
+/// # fn main() {
+/// rust_i18n::init_from_env!();
+/// # }
+/// ```
+#[macro_export(local_inner_macros)]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! init_from_env {
+    () => {
+        crate::_rust_i18n_init_from_env()
+    };
+}
+
+/// Get the translation of the key with the locale that it's found in, to tell the translations of the fallback locales.
+///
+/// The key is looked up like [`t!`], in the locale (default: the current locale) and then its fallback locales,
+/// and returns `None` if the key is missing in all of them. The placeholders are not replaced.
+///
+/// ```no_run
+/// rust_i18n::i18n!("locales", fallback = "en");
+///
+/// # fn main() {
+/// rust_i18n::lookup!("hello");
+/// // => Some(Resolved { value: "Hello", locale: "en", via_fallback: false })
+/// rust_i18n::lookup!("hello", locale = "zh-CN");
+/// // => Some(Resolved { value: "Hello", locale: "en", via_fallback: true })
+/// # }
+/// ```
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! lookup {
+    ($key:expr) => {
+        crate::_rust_i18n_lookup(&$crate::locale(), $key)
+    };
+    ($key:expr, locale = $locale:expr) => {
+        crate::_rust_i18n_lookup($locale, $key)
+    };
+}
+
+/// Set the global locale to the best match of the locale among the available locales, see [`set_locale`].
+///
+/// The locale is matched with the RFC 4647 lookup like [`negotiate!`], such as `de-AT` selects `de`,
+/// and the available locales include the ones of the custom backend.
+/// Returns the selected locale, or [`UnsupportedLocale`] without changing the locale if none matches.
+///
+/// ```no_run
+/// rust_i18n::i18n!("locales");
+///
+/// # fn main() {
+/// rust_i18n::try_set_locale!("de-AT");
+/// // => Ok("de")
+/// rust_i18n::try_set_locale!("xx");
+/// // => Err(UnsupportedLocale { locale: "xx" })
+/// # }
+/// ```
+#[macro_export(local_inner_macros)]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! try_set_locale {
+    ($locale:expr) => {
+        crate::_rust_i18n_try_set_locale($locale)
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{locale, CURRENT_LOCALE};
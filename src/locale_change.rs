@@ -0,0 +1,72 @@
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+type Callback = Arc<dyn Fn(&str) + Send + Sync>;
+
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+static CALLBACKS: Lazy<Mutex<Vec<(u64, Callback)>>> = Lazy::new(Default::default);
+
+/// Get the generation of the global locale, it increases each time [`set_locale`](crate::set_locale) changes the locale.
+///
+/// Compare it with a stored generation to check if the cached strings are outdated, without comparing the locales.
+///
+/// ```
+/// let generation = rust_i18n::locale_generation();
+/// // Rebuild the cached strings if `rust_i18n::locale_generation() != generation`
+/// ```
+pub fn locale_generation() -> u64 {
+    GENERATION.load(Ordering::Acquire)
+}
+
+/// Call `callback` with the new locale each time [`set_locale`](crate::set_locale) changes the global locale,
+/// until the returned guard is dropped.
+///
+/// The callback is called in the thread of `set_locale`, after [`locale_generation`] is increased.
+/// The thread locales of [`with_locale`](crate::with_locale) and [`set_thread_locale`](crate::set_thread_locale) do not call it.
+///
+/// ```
+/// let guard = rust_i18n::on_locale_change(|locale| {
+///     println!("The locale is changed to {}, redraw the UI", locale);
+/// });
+/// // Keep the callback for the lifetime of the app
+/// guard.detach();
+/// ```
+pub fn on_locale_change(callback: impl Fn(&str) + Send + Sync + 'static) -> LocaleChangeGuard {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    CALLBACKS.lock().unwrap().push((id, Arc::new(callback)));
+    LocaleChangeGuard(id)
+}
+
+/// The guard of [`on_locale_change`], the callback is removed when it's dropped.
+#[must_use = "the callback is removed when the guard is dropped, use `detach` to keep it"]
+pub struct LocaleChangeGuard(u64);
+
+impl LocaleChangeGuard {
+    /// Keep the callback until the program exits.
+    pub fn detach(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for LocaleChangeGuard {
+    fn drop(&mut self) {
+        CALLBACKS.lock().unwrap().retain(|(id, _)| *id != self.0);
+    }
+}
+
+/// Increase the generation and call the callbacks with the new locale.
+pub(crate) fn notify(locale: &str) {
+    GENERATION.fetch_add(1, Ordering::AcqRel);
+    // Call the callbacks without the lock, so they can subscribe, unsubscribe or set the locale.
+    let callbacks = CALLBACKS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(_, callback)| callback.clone())
+        .collect::<Vec<_>>();
+    for callback in callbacks {
+        callback(locale);
+    }
+}
@@ -0,0 +1,118 @@
+use once_cell::sync::Lazy;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, RwLock};
+
+/// The handler of the missing translations, called with the locale, the key, and the file and the line of the `t!` call.
+pub type MissingHandler = fn(locale: &str, key: &str, file: &str, line: u32);
+
+static HANDLER: RwLock<Option<MissingHandler>> = RwLock::new(None);
+static COLLECTING: AtomicBool = AtomicBool::new(false);
+static COLLECTED: Lazy<Mutex<BTreeMap<(String, String), MissingTranslation>>> =
+    Lazy::new(Default::default);
+
+/// Set the handler called each time `t!` misses a translation, or `None` to remove it.
+///
+/// The locale is the requested locale, the translation is missing in it and all its fallback locales.
+///
+/// ```
+/// fn report(locale: &str, key: &str, file: &str, line: u32) {
+///     eprintln!("missing `{}` in {} @ {}:{}", key, locale, file, line);
+/// }
+///
+/// rust_i18n::set_missing_handler(Some(report));
+/// ```
+pub fn set_missing_handler(handler: Option<MissingHandler>) {
+    *HANDLER.write().unwrap() = handler;
+}
+
+/// Start or stop collecting the missing translations of `t!`, see [`missing_translations`].
+///
+/// It works with the handler of [`set_missing_handler`], both of them are called.
+pub fn collect_missing(enabled: bool) {
+    COLLECTING.store(enabled, Ordering::Release);
+}
+
+/// A missing translation collected by [`collect_missing`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingTranslation {
+    /// The requested locale.
+    pub locale: String,
+    /// The translation key.
+    pub key: String,
+    /// The initial value to translate, as `rust-i18n-cli` generates it: the message of a minified key,
+    /// otherwise the last segment of the key.
+    pub value: String,
+    /// The file and the line of the `t!` calls, in the order they missed the translation.
+    pub locations: Vec<(String, u32)>,
+}
+
+/// Get the collected missing translations, sorted by the key and the locale.
+pub fn missing_translations() -> Vec<MissingTranslation> {
+    COLLECTED.lock().unwrap().values().cloned().collect()
+}
+
+/// Dump the collected missing translations as a v2 YAML locale file, ready to translate.
+///
+/// ```no_run
+/// rust_i18n::collect_missing(true);
+/// // Run the app...
+/// std::fs::write("TODO.yml", rust_i18n::missing_translations_yaml()).unwrap();
+/// ```
+pub fn missing_translations_yaml() -> String {
+    let mut trs: HashMap<String, HashMap<String, String>> = HashMap::new();
+    for missing in COLLECTED.lock().unwrap().values() {
+        trs.entry(missing.key.clone())
+            .or_default()
+            .insert(missing.locale.clone(), missing.value.clone());
+    }
+    rust_i18n_support::dump_translations_v2(&trs, rust_i18n_support::LocaleFormat::Yaml)
+}
+
+/// Remove the collected missing translations.
+pub fn clear_missing_translations() {
+    COLLECTED.lock().unwrap().clear();
+}
+
+/// Get the key to report, if there is a handler or the collector is enabled.
+#[doc(hidden)]
+pub fn _missing_key(key: &str) -> Option<String> {
+    if COLLECTING.load(Ordering::Acquire) || HANDLER.read().unwrap().is_some() {
+        Some(key.to_string())
+    } else {
+        None
+    }
+}
+
+/// Report a missing translation to the handler and the collector.
+#[doc(hidden)]
+pub fn _report_missing(locale: &str, key: &str, value: &str, file: &str, line: u32) {
+    let handler = *HANDLER.read().unwrap();
+    if let Some(handler) = handler {
+        handler(locale, key, file, line);
+    }
+
+    if !COLLECTING.load(Ordering::Acquire) {
+        return;
+    }
+    let mut collected = COLLECTED.lock().unwrap();
+    let missing = collected
+        .entry((key.to_string(), locale.to_string()))
+        .or_insert_with(|| MissingTranslation {
+            locale: locale.to_string(),
+            key: key.to_string(),
+            value: if key == value {
+                key.rsplit('.').next().unwrap_or(key).to_string()
+            } else {
+                value.to_string()
+            },
+            locations: vec![],
+        });
+    if !missing
+        .locations
+        .iter()
+        .any(|(f, l)| f == file && *l == line)
+    {
+        missing.locations.push((file.to_string(), line));
+    }
+}
@@ -0,0 +1,224 @@
+use crate::canonicalize_locale;
+use pin_project_lite::pin_project;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+thread_local! {
+    static THREAD_LOCALE: RefCell<Option<Arc<str>>> = const { RefCell::new(None) };
+}
+
+/// Get the locale of the current thread, if any.
+pub(crate) fn thread_locale() -> Option<Arc<str>> {
+    THREAD_LOCALE.with(|locale| locale.borrow().clone())
+}
+
+/// Set the locale of the current thread, it overrides the global locale of [`set_locale`](crate::set_locale) in this thread.
+///
+/// Use `None` to follow the global locale again.
+///
+/// ```
+/// rust_i18n::set_thread_locale(Some("de"));
+/// assert_eq!(&*rust_i18n::locale(), "de");
+///
+/// rust_i18n::set_thread_locale(None);
+/// assert_eq!(&*rust_i18n::locale(), "en");
+/// ```
+pub fn set_thread_locale(locale: Option<&str>) {
+    THREAD_LOCALE.with(|current| {
+        *current.borrow_mut() = locale.map(|locale| Arc::from(canonicalize_locale(locale)))
+    });
+}
+
+/// Replace the thread locale, and restore the previous one on drop, even if the scope panics.
+struct ThreadLocaleGuard(Option<Arc<str>>);
+
+impl ThreadLocaleGuard {
+    fn new(locale: Arc<str>) -> Self {
+        Self(THREAD_LOCALE.with(|current| current.replace(Some(locale))))
+    }
+}
+
+impl Drop for ThreadLocaleGuard {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        THREAD_LOCALE.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Run `f` with the locale of the current thread set to `locale`, and restore the previous one afterwards.
+///
+/// The [`t!`](crate::t) calls without `locale` argument in `f` use this locale instead of the global one,
+/// so the concurrent requests of a server can have different locales.
+/// The threads spawned in `f` do not inherit the locale.
+///
+/// With the `tracing` feature, `f` runs in a `with_locale` span with the `locale` field.
+///
+/// ```
+/// let locale = rust_i18n::with_locale("de", || rust_i18n::locale().to_string());
+/// assert_eq!(locale, "de");
+/// assert_eq!(&*rust_i18n::locale(), "en");
+/// ```
+pub fn with_locale<R>(locale: &str, f: impl FnOnce() -> R) -> R {
+    let locale: Arc<str> = Arc::from(canonicalize_locale(locale));
+    #[cfg(feature = "tracing")]
+    let _span = locale_span(&locale).entered();
+    let _guard = ThreadLocaleGuard::new(locale);
+    f()
+}
+
+/// The span of a scoped locale, so the logs show which locale is used.
+#[cfg(feature = "tracing")]
+fn locale_span(locale: &str) -> tracing::Span {
+    tracing::info_span!(target: "rust-i18n", "with_locale", locale)
+}
+
+/// Wrap a future to run with the locale, see [`with_locale`].
+///
+/// The locale is set on the polling thread while the future is polled, so it survives the `.await` points
+/// even if the executor moves the future to another thread.
+/// With the `tracing` feature, the future is polled in a `with_locale` span.
+///
+/// ```no_run
+/// # async fn handle_request() {}
+/// # async fn example() {
+/// rust_i18n::with_locale_async("de", async {
+///     handle_request().await;
+/// })
+/// .await;
+/// # }
+/// ```
+pub fn with_locale_async<F: Future>(locale: &str, future: F) -> WithLocale<F> {
+    let locale: Arc<str> = Arc::from(canonicalize_locale(locale));
+    WithLocale {
+        scope: LocaleScope {
+            #[cfg(feature = "tracing")]
+            span: locale_span(&locale),
+            locale,
+        },
+        future,
+    }
+}
+
+/// The locale of a [`WithLocale`], with its span.
+struct LocaleScope {
+    locale: Arc<str>,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+pin_project! {
+    /// The future returned by [`with_locale_async`].
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithLocale<F> {
+        scope: LocaleScope,
+        #[pin]
+        future: F,
+    }
+}
+
+impl<F: Future> Future for WithLocale<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        #[cfg(feature = "tracing")]
+        let _span = this.scope.span.enter();
+        let _guard = ThreadLocaleGuard::new(this.scope.locale.clone());
+        this.future.poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale;
+    use std::sync::Arc;
+    use std::task::{Wake, Waker};
+
+    /// A waker that does nothing, `Waker::noop` needs Rust 1.85.
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// A future that is pending on the first poll.
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    // The tests check the thread locale instead of the global locale, which other tests may change.
+
+    #[test]
+    fn test_with_locale() {
+        assert_eq!(thread_locale(), None);
+        let result = with_locale("de", || {
+            assert_eq!(&*locale(), "de");
+            with_locale("fr", || assert_eq!(&*locale(), "fr"));
+            assert_eq!(&*locale(), "de");
+            1
+        });
+        assert_eq!(result, 1);
+        assert_eq!(thread_locale(), None);
+
+        // Restored after panic
+        let result = std::panic::catch_unwind(|| with_locale("de", || panic!("failed")));
+        assert!(result.is_err());
+        assert_eq!(thread_locale(), None);
+
+        // Not inherited by other threads
+        with_locale("de", || {
+            let inherited = std::thread::spawn(thread_locale).join().unwrap();
+            assert_eq!(inherited, None);
+        });
+    }
+
+    #[test]
+    fn test_set_thread_locale() {
+        set_thread_locale(Some("ja"));
+        assert_eq!(&*locale(), "ja");
+        with_locale("de", || assert_eq!(&*locale(), "de"));
+        assert_eq!(&*locale(), "ja");
+        set_thread_locale(None);
+        assert_eq!(thread_locale(), None);
+    }
+
+    #[test]
+    fn test_canonical_thread_locale() {
+        with_locale("zh_cn", || assert_eq!(&*locale(), "zh-CN"));
+        set_thread_locale(Some("en-us"));
+        assert_eq!(&*locale(), "en-US");
+        set_thread_locale(None);
+    }
+
+    #[test]
+    fn test_with_locale_async() {
+        let mut future = Box::pin(with_locale_async("de", async {
+            assert_eq!(&*locale(), "de");
+            YieldNow(false).await;
+            assert_eq!(&*locale(), "de");
+            locale().to_string()
+        }));
+
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(thread_locale(), None);
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready("de".to_string()));
+        assert_eq!(thread_locale(), None);
+    }
+}
@@ -0,0 +1,31 @@
+rust_i18n::i18n!(
+    "./tests/locales",
+    fallback = "en",
+    check_placeholders = true
+);
+
+#[cfg(test)]
+mod tests {
+    use rust_i18n::t;
+
+    #[test]
+    fn test_t_with_checked_placeholders() {
+        assert_eq!(t!("hello"), "Bar - Hello, World!");
+        assert_eq!(
+            t!("messages.hello", locale = "en", name = "Jason"),
+            "Hello, Jason!"
+        );
+        assert_eq!(
+            t!("inbox.messages", locale = "en", count = 1),
+            "You have 1 new message."
+        );
+        assert_eq!(t!("rank", locale = "en", ordinal = 2), "2nd");
+        // Unknown keys and dynamic keys are not checked
+        assert_eq!(
+            t!("unknown.key", locale = "en", name = "Jason"),
+            "unknown.key"
+        );
+        let key = "messages.hello";
+        assert_eq!(t!(key, locale = "en"), "Hello, %{name}!");
+    }
+}
@@ -0,0 +1,8 @@
+#[test]
+fn test_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    // The date and time styles fail to compile without the `datetime` feature
+    #[cfg(not(feature = "datetime"))]
+    t.compile_fail("tests/ui/datetime/*.rs");
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "datetime")]
+
+use rust_i18n::t;
+
+rust_i18n::i18n!("./tests/datetime/locales", fallback = "en");
+
+#[test]
+fn test_datetime_format() {
+    let when = "2024-03-05 14:30:05";
+    assert_eq!(
+        t!("datetime_test", locale = "en", when = when),
+        "Due on March 5, 2024 (2:30\u{202F}PM)"
+    );
+    assert_eq!(
+        t!("datetime_test", locale = "de", when = when),
+        "Fällig am 5. März 2024 (14:30)"
+    );
+    assert_eq!(
+        t!("datetime_test", locale = "ja", when = when),
+        "期限：2024年3月5日（14:30）"
+    );
+    // Formatted with the locale that the translation is found in
+    assert_eq!(
+        t!("datetime_test", locale = "fr", when = when),
+        "Due on March 5, 2024 (2:30\u{202F}PM)"
+    );
+
+    // The styles at the call site
+    assert_eq!(
+        t!("Updated at %{when}", locale = "fr", when = when : {datetime_long}),
+        "Updated at 5 mars 2024 à 14:30:05"
+    );
+    assert_eq!(
+        t!("Updated at %{when}", locale = "en-GB", when = "2024-03-05T09:05:00Z" : {datetime_short}),
+        "Updated at 05/03/2024, 09:05"
+    );
+    // The values that are not dates are used as-is
+    assert_eq!(
+        t!("datetime_test", locale = "en", when = "soon"),
+        "Due on soon (soon)"
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_datetime_format_chrono() {
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+
+    let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+    assert_eq!(
+        t!("Due on %{when}", locale = "de", when = date : {date_long}),
+        "Due on 5. März 2024"
+    );
+    let offset = FixedOffset::east_opt(8 * 3600).unwrap();
+    let when = offset
+        .from_local_datetime(&date.and_hms_opt(14, 30, 5).unwrap())
+        .unwrap();
+    assert_eq!(
+        t!("Updated at %{when}", locale = "en", when = when : {datetime_long}),
+        "Updated at March 5, 2024 at 2:30:05\u{202F}PM GMT+8"
+    );
+    // The `Display` output is formatted by the styles of the translations
+    assert_eq!(
+        t!("datetime_test", locale = "de", when = when),
+        "Fällig am 5. März 2024 (14:30)"
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_datetime_format_time() {
+    use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+    let date = Date::from_calendar_date(2024, Month::March, 5).unwrap();
+    let when = PrimitiveDateTime::new(date, Time::from_hms(9, 5, 0).unwrap())
+        .assume_offset(UtcOffset::from_hms(-3, -30, 0).unwrap());
+    assert_eq!(
+        t!("Updated at %{when}", locale = "fr", when = when : {datetime_full}),
+        "Updated at mardi 5 mars 2024 à 09:05:00 UTC-3:30"
+    );
+    // The `Display` output is formatted by the styles of the translations
+    assert_eq!(
+        t!("datetime_test", locale = "en", when = when),
+        "Due on March 5, 2024 (9:05\u{202F}AM)"
+    );
+}
@@ -0,0 +1,42 @@
+rust_i18n::i18n!(
+    "./tests/locales",
+    fallback = "en",
+    fallback_map = { "zh-HK" = ["ja", "zh-CN"], "fr_ca" = "zh-CN" }
+);
+
+#[cfg(test)]
+mod tests {
+    use rust_i18n::t;
+
+    #[test]
+    fn test_fallback_map() {
+        // The mapped locales are looked up in order
+        assert_eq!(
+            t!("nested_locale_test", locale = "zh-HK"),
+            "こんにちは test"
+        );
+        assert_eq!(t!("hello", locale = "zh-HK"), "Bar - 你好世界！");
+        // Then the language territory and the `fallback` locales
+        assert_eq!(
+            t!("missing.lookup-fallback", locale = "zh-HK"),
+            "在 zh-XXX 中缺失的的翻译。"
+        );
+        assert_eq!(
+            t!("missing.default", locale = "zh-HK"),
+            "This is missing key fallbacked to en."
+        );
+        // Before the lookup of the language territory, the locales are canonicalized
+        assert_eq!(
+            t!("inbox.messages", locale = "fr-CA", count = 2),
+            "你有 2 条新消息。"
+        );
+        assert_eq!(
+            t!("inbox.messages", locale = "fr", count = 2),
+            "Vous avez 2 nouveaux messages."
+        );
+        // The mapped locales of the truncated locale
+        assert_eq!(t!("hello", locale = "zh-HK-x-private"), "Bar - 你好世界！");
+        // Not mapped
+        assert_eq!(t!("hello", locale = "ja"), "Bar - Hello, World!");
+    }
+}
@@ -0,0 +1,81 @@
+rust_i18n::i18n!(
+    "./tests/icu/locales",
+    fallback = "en",
+    message_format = "icu"
+);
+
+#[cfg(test)]
+mod tests {
+    use rust_i18n::t;
+
+    #[test]
+    fn test_plural() {
+        assert_eq!(t!("files", locale = "en", count = 0), "No files");
+        assert_eq!(t!("files", locale = "en", count = 1), "1 file");
+        assert_eq!(t!("files", locale = "en", count = 12), "12 files");
+        assert_eq!(t!("files", locale = "ru", count = 22), "22 файла");
+        assert_eq!(t!("files", locale = "ru", count = 25), "25 файлов");
+        assert_eq!(t!("files", locale = "ja", count = 2), "2 files");
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(
+            t!("liked", locale = "en", gender = "female"),
+            "She liked your post."
+        );
+        assert_eq!(t!("liked", locale = "en"), "They liked your post.");
+        assert_eq!(
+            t!("liked", locale = "fr", gender = "male"),
+            "Il a aimé votre publication."
+        );
+    }
+
+    #[test]
+    fn test_selectordinal() {
+        assert_eq!(t!("rank", locale = "en", place = 3), "3rd");
+        assert_eq!(t!("rank", locale = "en", place = 11), "11th");
+    }
+
+    #[test]
+    fn test_quoting() {
+        assert_eq!(
+            t!("quoted", locale = "en", name = "Jason"),
+            "Use {name} for placeholders, it's Jason."
+        );
+    }
+
+    #[test]
+    fn test_missing_key() {
+        assert_eq!(
+            t!("Hello, {name}!", locale = "en", name = "Jason"),
+            "Hello, Jason!"
+        );
+        assert_eq!(
+            t!(
+                "missing.greeting",
+                locale = "en",
+                default = "Hi, {name}!",
+                name = "Jason"
+            ),
+            "Hi, Jason!"
+        );
+        assert_eq!(
+            t!("Total: {n}", locale = "de", n = 1234.5 : {number}),
+            "Total: 1.234,5"
+        );
+    }
+
+    #[test]
+    fn test_fallback_locale() {
+        // The values are formatted with the locale of the translation
+        assert_eq!(
+            t!("total", locale = "de", n = 1234.5 : {number}),
+            "Total: 1,234.5"
+        );
+        assert_eq!(
+            t!("total", locale = "en", n = 1234.5 : {number}),
+            "Total: 1,234.5"
+        );
+    }
+}
@@ -120,6 +120,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(rust_i18n::negotiate!(&["zh-CN"]), "zh-CN");
+        assert_eq!(rust_i18n::negotiate!(&["zh-Hant-TW"]), "zh");
+        assert_eq!(rust_i18n::negotiate!(&["zh_cn"]), "zh-CN");
+        assert_eq!(rust_i18n::negotiate!(&["es", "de-AT", "fr"]), "de");
+        // From the custom backend
+        assert_eq!(rust_i18n::negotiate!(&["pt-BR"]), "pt");
+        // The fallback locale
+        assert_eq!(rust_i18n::negotiate!(&["es"]), "en");
+        assert_eq!(rust_i18n::negotiate!(&[] as &[&str]), "en");
+
+        let requested = rust_i18n::parse_accept_language("es;q=0.9, ja-JP, *;q=0.1");
+        assert_eq!(rust_i18n::negotiate!(&requested), "ja");
+    }
+
     #[test]
     fn test_t() {
         rust_i18n::set_locale("en");
@@ -167,6 +183,489 @@ mod tests {
         assert_eq!(t!("messages.other", count = a / 2), "You have 50 messages.");
     }
 
+    #[test]
+    fn test_t_with_count() {
+        rust_i18n::set_locale("en");
+
+        assert_eq!(t!("inbox.messages", count = 1), "You have 1 new message.");
+        assert_eq!(t!("inbox.messages", count = 0), "You have 0 new messages.");
+        assert_eq!(t!("inbox.messages", count = 2), "You have 2 new messages.");
+        assert_eq!(
+            t!("inbox.messages", count = 1.0 : {:.1}),
+            "You have 1.0 new messages."
+        );
+
+        assert_eq!(
+            t!("inbox.messages", locale = "fr", count = 0),
+            "Vous avez 0 nouveau message."
+        );
+        assert_eq!(
+            t!("inbox.messages", locale = "fr", count = 2),
+            "Vous avez 2 nouveaux messages."
+        );
+
+        assert_eq!(
+            t!("inbox.messages", locale = "ru", count = 21),
+            "У вас 21 новое сообщение."
+        );
+        assert_eq!(
+            t!("inbox.messages", locale = "ru", count = 3),
+            "У вас 3 новых сообщения."
+        );
+        assert_eq!(
+            t!("inbox.messages", locale = "ru", count = 11),
+            "У вас 11 новых сообщений."
+        );
+        assert_eq!(
+            t!("inbox.messages", locale = "ru", count = 1.5),
+            "У вас 1.5 нового сообщения."
+        );
+
+        // Languages without plural forms fallback to `other`
+        assert_eq!(
+            t!("inbox.messages", locale = "zh-CN", count = 1),
+            "你有 1 条新消息。"
+        );
+        // Missing plural forms use the fallback locale with its own rules
+        assert_eq!(
+            t!("inbox.messages", locale = "ja", count = 1),
+            "You have 1 new message."
+        );
+        // Plain keys still work with `count`
+        assert_eq!(
+            t!("messages.other", locale = "zh-CN", count = 1),
+            "你收到了 1 条新消息。"
+        );
+    }
+
+    #[test]
+    fn test_t_with_scoped_locale() {
+        assert_eq!(
+            rust_i18n::with_locale("zh-CN", || t!("hello")),
+            "Bar - 你好世界！"
+        );
+        assert_eq!(
+            rust_i18n::with_locale("zh-CN", || t!("hello", locale = "en")),
+            "Bar - Hello, World!"
+        );
+    }
+
+    #[test]
+    fn test_t_with_canonical_locales() {
+        // The locales of the files are canonicalized
+        assert_eq!(
+            t!("canonical_locale_test", locale = "en"),
+            "Hello canonical"
+        );
+        assert_eq!(
+            t!("canonical_locale_test", locale = "zh-CN"),
+            "你好 canonical"
+        );
+        // The `locale` argument is canonicalized
+        assert_eq!(t!("hello", locale = "zh_cn"), "Bar - 你好世界！");
+        assert_eq!(t!("hello", locale = "ZH-CN"), "Bar - 你好世界！");
+        assert_eq!(
+            t!("messages.hello", locale = "zh_CN", name = "Jason"),
+            "你好，Jason！"
+        );
+        assert_eq!(
+            t!("missing.lookup-fallback", locale = "ZH_hans_cn"),
+            "在 zh-XXX 中缺失的的翻译。"
+        );
+        // The current locale is canonicalized
+        assert_eq!(
+            rust_i18n::with_locale("zh_cn", || t!("hello")),
+            "Bar - 你好世界！"
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(
+            t!(
+                "missing.checkout.title",
+                locale = "en",
+                default = "Checkout"
+            ),
+            "Checkout"
+        );
+        assert_eq!(
+            t!(
+                "missing.checkout.greeting",
+                locale = "de",
+                default = "Hi, %{name}!",
+                name = "Jason"
+            ),
+            "Hi, Jason!"
+        );
+        // Only a string literal is the default text, other values fill the `%{default}` placeholder
+        let default = String::from("Dynamic");
+        assert_eq!(
+            t!("Default: %{default}", locale = "en", default = default),
+            "Default: Dynamic"
+        );
+
+        // The translations and the fallback locales are used first
+        assert_eq!(
+            t!("hello", locale = "en", default = "Hi"),
+            "Bar - Hello, World!"
+        );
+        assert_eq!(
+            t!("missing.default", locale = "zh-CN", default = "Default"),
+            "This is missing key fallbacked to en."
+        );
+        assert_eq!(
+            t!(
+                "messages.hello",
+                locale = "en",
+                default = "Hi",
+                name = "Jason"
+            ),
+            "Hello, Jason!"
+        );
+    }
+
+    #[test]
+    fn test_strict_argument_without_strict_option() {
+        // The `strict` argument is only reserved with the `strict` option
+        let mode = "on";
+        assert_eq!(
+            t!("Strict: %{strict}", locale = "en", strict = mode),
+            "Strict: on"
+        );
+    }
+
+    #[test]
+    fn test_currency_and_unit_format() {
+        assert_eq!(
+            t!("currency_test", locale = "en", amount = -1234.5, fee = 0.25),
+            "Balance: -$1,234.50 (0.25% fee)"
+        );
+        assert_eq!(
+            t!("currency_test", locale = "de", amount = 1234.5, fee = 0.25),
+            "Kontostand: 1.234,50\u{A0}$ (0,25\u{A0}% Gebühr)"
+        );
+        assert_eq!(
+            t!(
+                "download_test",
+                locale = "fr",
+                size = 1536000,
+                distance = 12.5
+            ),
+            "Téléchargé 1,5\u{A0}Mo de 12,5\u{A0}km"
+        );
+
+        // The styles at the call site
+        assert_eq!(
+            t!("Total: %{amount}", locale = "ja", amount = 1234.5 : {currency:JPY}),
+            "Total: ￥1,234"
+        );
+        assert_eq!(
+            t!("Total: %{amount}", locale = "en", amount = 1234.5 : {currency_code:EUR}),
+            "Total: EUR\u{A0}1,234.50"
+        );
+
+        // The standalone functions format in the current locale
+        rust_i18n::with_locale("de", || {
+            assert_eq!(rust_i18n::format_currency(1234.5, "EUR"), "1.234,50\u{A0}€");
+            assert_eq!(
+                rust_i18n::format_currency_code(1234.5, "EUR"),
+                "1.234,50\u{A0}EUR"
+            );
+            assert_eq!(
+                rust_i18n::format_unit(12.5, rust_i18n::Unit::Kilometer),
+                "12,5\u{A0}km"
+            );
+            assert_eq!(rust_i18n::format_bytes(2_500_000_000u64), "2,5\u{A0}GB");
+        });
+    }
+
+    #[test]
+    fn test_relative_and_duration_format() {
+        assert_eq!(
+            t!(
+                "relative_test",
+                locale = "en",
+                updated = -180,
+                elapsed = 3900
+            ),
+            "Updated 3 minutes ago, took 1 h 5 min"
+        );
+        assert_eq!(
+            t!("relative_test", locale = "de", updated = -180, elapsed = 45),
+            "Aktualisiert vor 3 Minuten, dauerte 45 Sek."
+        );
+        assert_eq!(
+            t!("relative_test", locale = "ru", updated = -300, elapsed = 90),
+            "Обновлено 5 минут назад, заняло 1 мин 30 с"
+        );
+        assert_eq!(
+            t!("Next run %{at}", locale = "de", at = 7200 : {relative}),
+            "Next run in 2 Stunden"
+        );
+        assert_eq!(
+            t!("Elapsed: %{secs}", locale = "zh-CN", secs = 3900 : {duration}),
+            "Elapsed: 1小时5分钟"
+        );
+    }
+
+    #[test]
+    fn test_list_format() {
+        let names = vec!["Alice".to_string(), "Bob".to_string(), "Carol".to_string()];
+        assert_eq!(
+            t!("shared_with_test", locale = "en", users = names : {list}),
+            "Shared with Alice, Bob, and Carol"
+        );
+        assert_eq!(
+            t!("shared_with_test", locale = "zh-CN", users = names : {list}),
+            "已与Alice、Bob和Carol共享"
+        );
+        // The style of the translation is used
+        assert_eq!(
+            t!("shared_with_test", locale = "de", users = names : {list}),
+            "Geteilt mit Alice, Bob oder Carol"
+        );
+        // The items are borrowed
+        assert_eq!(names.len(), 3);
+        assert_eq!(
+            t!("Pick %{colors}", locale = "en", colors = ["red", "blue"] : {list_or}),
+            "Pick red or blue"
+        );
+
+        // A style other than a list style in the translation formats the list in the `list` style
+        assert_eq!(
+            t!("shared_with_test", locale = "fr", users = names : {list}),
+            "Partagé avec Alice, Bob et Carol"
+        );
+        // The items are taken by reference, such as the fields and the slices
+        struct Team {
+            members: Vec<String>,
+        }
+        let team = Team {
+            members: vec!["Dave".to_string(), "Eve".to_string()],
+        };
+        assert_eq!(
+            t!("shared_with_test", locale = "en", users = team.members : {list}),
+            "Shared with Dave and Eve"
+        );
+        assert_eq!(team.members.len(), 2);
+        assert_eq!(
+            t!("shared_with_test", locale = "en", users = &names[1..] : {list}),
+            "Shared with Bob and Carol"
+        );
+        // The items are kept as-is
+        let items = ["a\u{1F}b", "c"];
+        assert_eq!(
+            t!("shared_with_test", locale = "en", users = items : {list}),
+            "Shared with a\u{1F}b and c"
+        );
+
+        rust_i18n::with_locale("zh-CN", || {
+            assert_eq!(
+                rust_i18n::format_list(&names, rust_i18n::ListStyle::And),
+                "Alice、Bob和Carol"
+            );
+        });
+    }
+
+    #[test]
+    fn test_number_format() {
+        assert_eq!(
+            t!(
+                "number_test",
+                locale = "en",
+                amount = 1234567.5,
+                rate = 0.125
+            ),
+            "Total: 1,234,567.5 (12.5%)"
+        );
+        assert_eq!(
+            t!(
+                "number_test",
+                locale = "de",
+                amount = 1234567.5,
+                rate = 0.125
+            ),
+            "Summe: 1.234.567,5 (12,5\u{A0}%)"
+        );
+        // Formatted with the locale that the translation is found in
+        assert_eq!(
+            t!(
+                "number_test",
+                locale = "de-AT",
+                amount = 1234567.5,
+                rate = 0.125
+            ),
+            "Summe: 1.234.567,5 (12,5\u{A0}%)"
+        );
+        assert_eq!(
+            t!("number_test", locale = "fr", amount = -1234, rate = 1),
+            "Total: -1,234 (100%)"
+        );
+
+        // The format of the call site
+        assert_eq!(
+            t!("call_site_number_test", locale = "de", amount = 1234567.5 : {number}),
+            "Summe: 1.234.567,5"
+        );
+        assert_eq!(
+            t!("call_site_number_test", locale = "de", amount = 0.5 : {percent}),
+            "Summe: 50\u{A0}%"
+        );
+        assert_eq!(
+            t!("call_site_number_test", locale = "de", amount = 1234567.5),
+            "Summe: 1234567.5"
+        );
+        assert_eq!(
+            t!("Total: %{n}", locale = "ar", n = 1234 : {number}),
+            "Total: ١٬٢٣٤"
+        );
+        // With the fraction digits
+        assert_eq!(
+            t!("call_site_number_test", locale = "de", amount = 1234.5 : {number:.2}),
+            "Summe: 1.234,50"
+        );
+        assert_eq!(
+            t!("Rate: %{rate:percent:.1}", locale = "en", rate = 0.12345),
+            "Rate: 12.3%"
+        );
+        // The plural form is selected with the number before formatting
+        assert_eq!(
+            t!("inbox.messages", locale = "en", count = 1 : {number}),
+            "You have 1 new message."
+        );
+        assert_eq!(
+            t!("inbox.messages", locale = "en", count = 1234 : {number}),
+            "You have 1,234 new messages."
+        );
+        // Not a number
+        assert_eq!(
+            t!("number_test", locale = "en", amount = "many", rate = 0.5),
+            "Total: many (50%)"
+        );
+    }
+
+    #[test]
+    fn test_lookup() {
+        use rust_i18n::Resolved;
+
+        assert_eq!(
+            rust_i18n::lookup!("hello", locale = "zh-CN"),
+            Some(Resolved {
+                value: "Bar - 你好世界！".into(),
+                locale: "zh-CN".into(),
+                via_fallback: false,
+            })
+        );
+        assert_eq!(
+            rust_i18n::with_locale("zh_cn", || rust_i18n::lookup!("hello")),
+            rust_i18n::lookup!("hello", locale = "zh-CN")
+        );
+
+        // From the fallback locales
+        let resolved = rust_i18n::lookup!("missing.default", locale = "zh-CN").unwrap();
+        assert_eq!(resolved.value, "This is missing key fallbacked to en.");
+        assert_eq!(resolved.locale, "en");
+        assert!(resolved.via_fallback);
+        let resolved = rust_i18n::lookup!("missing.lookup-fallback", locale = "zh-HK").unwrap();
+        assert_eq!(resolved.locale, "zh");
+        assert!(resolved.via_fallback);
+
+        // From the custom backend
+        let resolved = rust_i18n::lookup!("foo", locale = "pt").unwrap();
+        assert_eq!(resolved.value, "pt-fake.foo");
+        assert!(!resolved.via_fallback);
+
+        // The links are resolved, the placeholders are kept
+        assert_eq!(
+            rust_i18n::lookup!("links.welcome", locale = "en")
+                .unwrap()
+                .value,
+            "Welcome to Rust I18n, %{name}."
+        );
+
+        assert_eq!(rust_i18n::lookup!("not.exists", locale = "en"), None);
+    }
+
+    #[test]
+    fn test_t_with_linked_messages() {
+        assert_eq!(
+            t!("links.welcome", locale = "en", name = "Jason"),
+            "Welcome to Rust I18n, Jason."
+        );
+        assert_eq!(
+            t!("links.nested", locale = "en", name = "Jason"),
+            "Welcome to Rust I18n, Jason. Enjoy Rust I18n!"
+        );
+        // The linked message is looked up with the fallback locales
+        assert_eq!(
+            t!("links.welcome", locale = "zh-CN", name = "Jason"),
+            "欢迎使用 Rust I18n，Jason。"
+        );
+        assert_eq!(
+            t!("links.nested", locale = "zh-CN", name = "Jason"),
+            "欢迎使用 Rust I18n，Jason。 Enjoy Rust I18n!"
+        );
+        assert_eq!(t!("links.unknown", locale = "en"), "Hello @:unknown.key.");
+        assert_eq!(
+            t!("links.escaped", locale = "en"),
+            "Write @:brand.name to link Rust I18n."
+        );
+        // Each key is resolved once, instead of 4^12 times
+        assert_eq!(t!("link_repeat_test.l0", locale = "en"), "");
+    }
+
+    #[test]
+    fn test_t_with_escaped_placeholders() {
+        assert_eq!(
+            t!("escape.placeholder", locale = "en", name = "Jason"),
+            "Use %{name} to show the name, like Jason."
+        );
+        assert_eq!(
+            t!("escape.literal", locale = "en"),
+            "Placeholders look like %{name}."
+        );
+        assert_eq!(
+            t!("escape.literal", locale = "en", name = "Jason"),
+            "Placeholders look like %{name}."
+        );
+
+        // Literal messages without translations
+        assert_eq!(
+            t!("Type %%{name} or %{name}", locale = "en", name = "Jason"),
+            "Type %{name} or Jason"
+        );
+        assert_eq!(t!("Type %%{name}", locale = "en"), "Type %{name}");
+        assert_eq!(
+            t!("%{a}%%{b}%{c}", locale = "en", a = 1, b = 2, c = 3),
+            "1%{b}3"
+        );
+        assert_eq!(
+            t!("{%{a}} %{%{b}}", locale = "en", a = 1, b = 2),
+            "{1} %{2}"
+        );
+        assert_eq!(t!("100%% %%%{a}", locale = "en", a = 1), "100%% %1");
+    }
+
+    #[test]
+    fn test_t_with_ordinal() {
+        rust_i18n::set_locale("en");
+
+        let ranks: Vec<_> = [1, 2, 3, 4, 11, 12, 13, 21, 102]
+            .iter()
+            .map(|place| t!("rank", ordinal = place).to_string())
+            .collect();
+        assert_eq!(
+            ranks,
+            ["1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "102nd"]
+        );
+
+        assert_eq!(t!("rank", locale = "fr", ordinal = 1), "1er");
+        assert_eq!(t!("rank", locale = "fr", ordinal = 2), "2e");
+        // Missing ordinal forms use the fallback locale with its own rules
+        assert_eq!(t!("rank", locale = "de", ordinal = 2), "2nd");
+    }
+
     #[test]
     fn test_t_with_locale_and_args() {
         rust_i18n::set_locale("en");
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    // The global locale is shared by the threads, so all the checks are in one test.
+    #[test]
+    fn test_on_locale_change() {
+        let changes = Arc::new(Mutex::new(vec![]));
+        let guard = rust_i18n::on_locale_change({
+            let changes = changes.clone();
+            move |locale| {
+                let generation = rust_i18n::locale_generation();
+                changes
+                    .lock()
+                    .unwrap()
+                    .push((locale.to_string(), generation));
+            }
+        });
+
+        let generation = rust_i18n::locale_generation();
+        rust_i18n::set_locale("zh_cn");
+        // Not changed
+        rust_i18n::set_locale("zh-CN");
+        rust_i18n::with_locale("fr", || {});
+        rust_i18n::set_locale("en");
+        assert_eq!(rust_i18n::locale_generation(), generation + 2);
+        assert_eq!(
+            *changes.lock().unwrap(),
+            vec![
+                ("zh-CN".to_string(), generation + 1),
+                ("en".to_string(), generation + 2)
+            ]
+        );
+
+        // The callbacks can set the locale
+        let nested = rust_i18n::on_locale_change(|locale| {
+            if locale == "de" {
+                rust_i18n::set_locale("fr");
+            }
+        });
+        rust_i18n::set_locale("de");
+        assert_eq!(&*rust_i18n::locale(), "fr");
+        drop(nested);
+
+        drop(guard);
+        changes.lock().unwrap().clear();
+        rust_i18n::set_locale("ja");
+        assert_eq!(rust_i18n::locale_generation(), generation + 5);
+        assert!(changes.lock().unwrap().is_empty());
+    }
+}
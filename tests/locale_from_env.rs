@@ -0,0 +1,52 @@
+rust_i18n::i18n!("./tests/locales", fallback = "en", locale_from_env = true);
+
+#[cfg(test)]
+mod tests {
+    use rust_i18n::t;
+    use std::sync::{Arc, Mutex};
+
+    // The environment variables are shared by the threads, so all the checks are in one test.
+    #[test]
+    fn test_locale_from_env() {
+        std::env::set_var("LC_ALL", "");
+        std::env::set_var("LC_MESSAGES", "");
+        std::env::set_var("LANGUAGE", "es:zh_TW");
+        std::env::set_var("LANG", "ja_JP.UTF-8");
+
+        // The callbacks are called after the backend is initialized, so they can use the translations
+        let changes = Arc::new(Mutex::new(vec![]));
+        let guard = rust_i18n::on_locale_change({
+            let changes = changes.clone();
+            move |locale| {
+                changes
+                    .lock()
+                    .unwrap()
+                    .push((locale.to_string(), t!("hello").to_string()))
+            }
+        });
+
+        // Applied when the translations are first used
+        assert_eq!(t!("missing.lookup-fallback"), "在 zh-XXX 中缺失的的翻译。");
+        assert_eq!(&*rust_i18n::locale(), "zh");
+        drop(guard);
+        assert_eq!(
+            *changes.lock().unwrap(),
+            vec![("zh".to_string(), "Bar - Hello, World!".to_string())]
+        );
+
+        assert_eq!(rust_i18n::detect_locale!(), Some("zh"));
+        std::env::set_var("LANGUAGE", "");
+        assert_eq!(rust_i18n::detect_locale!(), Some("ja"));
+        assert_eq!(rust_i18n::init_from_env!(), Some("ja"));
+        assert_eq!(&*rust_i18n::locale(), "ja");
+
+        std::env::set_var("LANG", "C.UTF-8");
+        std::env::set_var("LANGUAGE", "de");
+        assert_eq!(rust_i18n::detect_locale!(), None);
+        std::env::set_var("LC_MESSAGES", "es_ES.UTF-8");
+        assert_eq!(rust_i18n::detect_locale!(), Some("de"));
+        std::env::set_var("LANGUAGE", "");
+        assert_eq!(rust_i18n::init_from_env!(), None);
+        assert_eq!(&*rust_i18n::locale(), "ja");
+    }
+}
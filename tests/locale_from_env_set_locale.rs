@@ -0,0 +1,16 @@
+rust_i18n::i18n!("./tests/locales", fallback = "en", locale_from_env = true);
+
+#[cfg(test)]
+mod tests {
+    use rust_i18n::t;
+
+    #[test]
+    fn test_set_locale_before_locale_from_env() {
+        std::env::set_var("LC_ALL", "ja_JP.UTF-8");
+
+        // The locale set before the translations are first used is kept
+        rust_i18n::set_locale("zh-CN");
+        assert_eq!(t!("hello"), "Bar - 你好世界！");
+        assert_eq!(&*rust_i18n::locale(), "zh-CN");
+    }
+}
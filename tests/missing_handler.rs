@@ -0,0 +1,89 @@
+use rust_i18n::t;
+use std::sync::Mutex;
+
+rust_i18n::i18n!("./tests/locales");
+
+static REPORTED: Mutex<Vec<(String, String, u32)>> = Mutex::new(Vec::new());
+
+fn report(locale: &str, key: &str, file: &str, line: u32) {
+    assert!(file.ends_with("missing_handler.rs"));
+    REPORTED
+        .lock()
+        .unwrap()
+        .push((locale.to_string(), key.to_string(), line));
+}
+
+fn translate_missing() -> (u32, u32) {
+    let line = line!() + 1;
+    let _ = t!("missing.todo.title", locale = "fr");
+    let _ = t!("missing.todo.greeting", locale = "de", name = "Jason");
+    (line, line + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The handler and the collector are global, so all the checks are in one test.
+    #[test]
+    fn test_missing_handler() {
+        // No report without a handler or the collector
+        translate_missing();
+        assert!(REPORTED.lock().unwrap().is_empty());
+        assert!(rust_i18n::missing_translations().is_empty());
+
+        rust_i18n::set_missing_handler(Some(report));
+        assert_eq!(t!("hello", locale = "en"), "Bar - Hello, World!");
+        assert!(REPORTED.lock().unwrap().is_empty());
+
+        let (line1, line2) = translate_missing();
+        assert_eq!(
+            *REPORTED.lock().unwrap(),
+            vec![
+                ("fr".to_string(), "missing.todo.title".to_string(), line1),
+                ("de".to_string(), "missing.todo.greeting".to_string(), line2),
+            ]
+        );
+
+        rust_i18n::set_missing_handler(None);
+        rust_i18n::collect_missing(true);
+        translate_missing();
+        translate_missing();
+        let _ = t!("missing.todo.title", locale = "zh_cn");
+        assert_eq!(REPORTED.lock().unwrap().len(), 2);
+
+        let missing = rust_i18n::missing_translations();
+        assert_eq!(
+            missing
+                .iter()
+                .map(|m| (m.key.as_str(), m.locale.as_str(), m.value.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("missing.todo.greeting", "de", "greeting"),
+                ("missing.todo.title", "fr", "title"),
+                ("missing.todo.title", "zh-CN", "title"),
+            ]
+        );
+        // The locations are deduplicated
+        assert_eq!(missing[1].locations.len(), 1);
+        assert_eq!(missing[1].locations[0].1, line1);
+
+        // The dump is a v2 locale file
+        let yaml = rust_i18n::missing_translations_yaml();
+        assert!(yaml.starts_with("_version: 2\n"));
+        let dir = std::env::temp_dir().join(format!("rust-i18n-missing-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("TODO.yml"), yaml).unwrap();
+        let trs = rust_i18n_support::load_locales(dir.to_str().unwrap(), |_| false);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(trs["fr"]["missing.todo.title"], "title");
+        assert_eq!(trs["zh-CN"]["missing.todo.title"], "title");
+        assert_eq!(trs["de"]["missing.todo.greeting"], "greeting");
+
+        rust_i18n::clear_missing_translations();
+        assert!(rust_i18n::missing_translations().is_empty());
+        rust_i18n::collect_missing(false);
+        translate_missing();
+        assert!(rust_i18n::missing_translations().is_empty());
+    }
+}
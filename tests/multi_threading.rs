@@ -1,4 +1,4 @@
-use rust_i18n::{set_locale, t};
+use rust_i18n::{set_locale, t, with_locale};
 use std::ops::Add;
 use std::thread::spawn;
 use std::time::{Duration, Instant};
@@ -71,3 +71,37 @@ fn test_t_concurrent() {
         task.join().unwrap();
     }
 }
+
+#[test]
+fn test_with_locale_concurrent() {
+    let end = Instant::now().add(Duration::from_secs(3));
+    let store = spawn(move || {
+        let mut i = 0u32;
+        while Instant::now() < end {
+            for _ in 0..100 {
+                i = i.wrapping_add(1);
+                set_locale(&format!("en-{i}"));
+            }
+        }
+    });
+    let tasks: Vec<_> = ["de", "fr", "ja", "zh-CN"]
+        .into_iter()
+        .map(|locale| {
+            spawn(move || {
+                while Instant::now() < end {
+                    with_locale(locale, || {
+                        for _ in 0..100 {
+                            assert_eq!(&*rust_i18n::locale(), locale);
+                            t!("hello");
+                        }
+                    });
+                    assert!(rust_i18n::locale().starts_with("en"));
+                }
+            })
+        })
+        .collect();
+    store.join().unwrap();
+    for task in tasks {
+        task.join().unwrap();
+    }
+}
@@ -0,0 +1,39 @@
+use rust_i18n::t;
+
+rust_i18n::i18n!("./tests/locales", pseudo_locales = true);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The global locale is shared by the threads, so all the checks are in one test.
+    #[test]
+    fn test_pseudo_locales() {
+        let locales = rust_i18n::available_locales!();
+        assert!(locales.contains(&"en-XA"));
+        assert!(locales.contains(&"ar-XB"));
+
+        assert_eq!(
+            t!("messages.hello", locale = "en-XA", name = "Jason"),
+            "[Ĥéļļö, Jason! ~~~~]"
+        );
+        assert_eq!(
+            t!("links.welcome", locale = "en-XA", name = "Jason"),
+            "[Ŵéļçöɱé ţö [Ŕûšţ Î18ñ ~~~~], Jason. ~~~~~~]"
+        );
+        assert_eq!(
+            t!("messages", locale = "en-XA", count = 2),
+            "[Ýöû ĥáṽé 2 ɱéššáĝéš. ~~~~~~~~]"
+        );
+        assert_eq!(
+            t!("messages.hello", locale = "ar-XB", name = "Jason"),
+            "\u{200F}\u{202E}Hello,\u{202C}\u{200F} Jason\u{200F}\u{202E}!\u{202C}\u{200F}"
+        );
+        // Not generated for the other locales
+        assert_eq!(t!("hello", locale = "zh-CN"), "Bar - 你好世界！");
+
+        rust_i18n::set_locale("en_xa");
+        assert_eq!(t!("hello"), "[Ɓáŕ - Ĥéļļö, Ŵöŕļð! ~~~~~~~~]");
+        rust_i18n::set_locale("en");
+    }
+}
@@ -0,0 +1,46 @@
+rust_i18n::i18n!("./tests/locales", fallback = "en", strict = true);
+
+#[cfg(test)]
+mod tests {
+    use rust_i18n::t;
+
+    #[test]
+    fn test_t_with_strict() {
+        assert_eq!(t!("hello", locale = "en"), "Bar - Hello, World!");
+        assert_eq!(
+            t!("messages.hello", locale = "en", name = "Jason"),
+            "Hello, Jason!"
+        );
+        // Only the plural forms are defined
+        assert_eq!(
+            t!("inbox.messages", locale = "en", count = 2),
+            "You have 2 new messages."
+        );
+        assert_eq!(t!("rank", locale = "en", ordinal = 3), "3rd");
+    }
+
+    #[test]
+    fn test_t_with_strict_opt_out() {
+        assert_eq!(
+            t!("unknown.key", locale = "en", strict = false),
+            "unknown.key"
+        );
+        assert_eq!(
+            t!(
+                "unknown.%{name}",
+                locale = "en",
+                name = "Jason",
+                strict = false
+            ),
+            "unknown.Jason"
+        );
+        // The keys with the `default` text are not checked
+        assert_eq!(
+            t!("checkout.title", locale = "en", default = "Checkout"),
+            "Checkout"
+        );
+        // Dynamic keys are not checked
+        let key = "unknown.dynamic";
+        assert_eq!(t!(key, locale = "en"), "unknown.dynamic");
+    }
+}
@@ -0,0 +1,26 @@
+rust_i18n::i18n!(
+    "./tests/locales",
+    fallback = "en",
+    strict = true,
+    minify_key = true,
+    minify_key_len = 24,
+    minify_key_prefix = "t_",
+    minify_key_thresh = 4
+);
+
+#[cfg(test)]
+mod tests {
+    use rust_i18n::t;
+
+    #[test]
+    fn test_t_with_strict_minify_key() {
+        assert_eq!(
+            t!("Hello, strict mode!", locale = "en"),
+            "Hello, strict mode!"
+        );
+        assert_eq!(
+            t!("Hello, strict mode!", locale = "zh-CN"),
+            "你好，严格模式！"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "tracing")]
+
+use std::fmt::Write;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Wake, Waker};
+
+use rust_i18n::t;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+rust_i18n::i18n!("./tests/locales", fallback = "en");
+
+/// A waker that does nothing, `Waker::noop` needs Rust 1.85.
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Format the fields as `name=value`.
+#[derive(Default)]
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        write!(self.0, "{}={:?}", field.name(), value).unwrap();
+    }
+}
+
+/// Record the events with the fields of the entered spans.
+#[derive(Clone, Default)]
+struct Recorder {
+    next_id: Arc<AtomicU64>,
+    spans: Arc<Mutex<Vec<(u64, String)>>>,
+    stack: Arc<Mutex<Vec<u64>>>,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+        let name = format!("{}{{{}}}", span.metadata().name(), fields.0);
+        self.spans.lock().unwrap().push((id, name));
+        Id::from_u64(id)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let spans = self.spans.lock().unwrap();
+        let mut line = String::new();
+        for id in self.stack.lock().unwrap().iter() {
+            let (_, name) = spans.iter().find(|(span, _)| span == id).unwrap();
+            write!(line, "{}: ", name).unwrap();
+        }
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        write!(line, "{} {}", event.metadata().level(), fields.0).unwrap();
+        self.events.lock().unwrap().push(line);
+    }
+
+    fn enter(&self, span: &Id) {
+        self.stack.lock().unwrap().push(span.into_u64());
+    }
+
+    fn exit(&self, _: &Id) {
+        self.stack.lock().unwrap().pop();
+    }
+}
+
+#[test]
+fn test_tracing() {
+    let recorder = Recorder::default();
+    let line = tracing::subscriber::with_default(recorder.clone(), || {
+        assert_eq!(t!("hello", locale = "en"), "Bar - Hello, World!");
+        let line = line!() + 1;
+        let _ = t!("missing.tracing", locale = "zh_cn");
+        rust_i18n::with_locale("de", || {
+            let _ = t!("missing.tracing", name = "Jason");
+        });
+
+        let mut future = Box::pin(rust_i18n::with_locale_async("fr", async {
+            let _ = t!("missing.tracing");
+        }));
+        let waker = Waker::from(Arc::new(NoopWaker));
+        assert!(future
+            .as_mut()
+            .poll(&mut Context::from_waker(&waker))
+            .is_ready());
+        line
+    });
+
+    let file = file!();
+    assert_eq!(
+        *recorder.events.lock().unwrap(),
+        vec![
+            format!(
+                r#"WARN message=missing translation locale="zh-CN" key=missing.tracing fallback_chain=["zh-CN", "zh", "en"] file="{}" line={}"#,
+                file, line
+            ),
+            format!(
+                r#"with_locale{{locale="de"}}: WARN message=missing translation locale="de" key=missing.tracing fallback_chain=["de", "en"] file="{}" line={}"#,
+                file,
+                line + 2
+            ),
+            format!(
+                r#"with_locale{{locale="fr"}}: WARN message=missing translation locale="fr" key=missing.tracing fallback_chain=["fr", "en"] file="{}" line={}"#,
+                file,
+                line + 6
+            ),
+        ]
+    );
+}
@@ -0,0 +1,40 @@
+struct TestBackend;
+
+impl rust_i18n::Backend for TestBackend {
+    fn available_locales(&self) -> Vec<&str> {
+        vec!["es-MX"]
+    }
+
+    fn translate(&self, _locale: &str, _key: &str) -> Option<&str> {
+        None
+    }
+}
+
+rust_i18n::i18n!("./tests/locales", fallback = "en", backend = TestBackend);
+
+#[cfg(test)]
+mod tests {
+    // The global locale is shared by the threads, so all the checks are in one test.
+    #[test]
+    fn test_try_set_locale() {
+        assert_eq!(rust_i18n::try_set_locale!("zh-CN"), Ok("zh-CN"));
+        assert_eq!(&*rust_i18n::locale(), "zh-CN");
+        assert_eq!(rust_i18n::try_set_locale!("de_AT"), Ok("de"));
+        assert_eq!(rust_i18n::try_set_locale!("zh-Hant-TW"), Ok("zh"));
+        // From the custom backend
+        assert_eq!(rust_i18n::try_set_locale!("es-MX"), Ok("es-MX"));
+        assert_eq!(&*rust_i18n::locale(), "es-MX");
+
+        let err = rust_i18n::try_set_locale!("xx").unwrap_err();
+        assert_eq!(err.locale, "xx");
+        assert_eq!(err.to_string(), "The locale `xx` is not available");
+        assert_eq!(
+            rust_i18n::try_set_locale!("es"),
+            Err(rust_i18n::UnsupportedLocale {
+                locale: "es".into()
+            })
+        );
+        // Not changed
+        assert_eq!(&*rust_i18n::locale(), "es-MX");
+    }
+}
@@ -0,0 +1,33 @@
+rust_i18n::i18n!("./tests/locales", fallback = "en", typed_keys = "tr");
+
+#[cfg(test)]
+mod tests {
+    use crate::tr;
+
+    #[test]
+    fn test_typed_keys() {
+        assert_eq!(tr::hello(), "Bar - Hello, World!");
+        assert_eq!(tr::messages::hello("Jason"), "Hello, Jason!");
+        assert_eq!(
+            tr::a::very::nested::message("Jason", "Bla bla"),
+            "Hello, Jason. Your message is: Bla bla"
+        );
+        assert_eq!(tr::custom::greeting(123), "Hello, 123!!!");
+        assert!(tr::lorem_ipsum().starts_with("Lorem ipsum dolor sit amet"));
+    }
+
+    #[test]
+    fn test_typed_keys_with_nested_keys() {
+        assert_eq!(tr::nested_locale_test(), "Hello test");
+        assert_eq!(tr::nested_locale_test::hello(), "Hello test2");
+        assert_eq!(tr::nested_locale_test::hello::world(), "Hello test3");
+    }
+
+    #[test]
+    fn test_typed_keys_with_plural() {
+        assert_eq!(tr::inbox::messages(1), "You have 1 new message.");
+        assert_eq!(tr::inbox::messages(5), "You have 5 new messages.");
+        assert_eq!(tr::rank(1), "1st");
+        assert_eq!(tr::rank(23), "23rd");
+    }
+}
@@ -0,0 +1,4 @@
+// The path is relative to the test crate in `target/tests/trybuild/rust-i18n`
+rust_i18n::i18n!("../../../../tests/ui/datetime/locales", fallback = "en");
+
+fn main() {}
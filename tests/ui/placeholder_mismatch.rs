@@ -0,0 +1,6 @@
+// The path is relative to the test crate in `target/tests/trybuild/rust-i18n`
+rust_i18n::i18n!("../../../../tests/locales", fallback = "en", check_placeholders = true);
+
+fn main() {
+    let _ = rust_i18n::t!("messages.hello", locale = "en", username = "Jason");
+}
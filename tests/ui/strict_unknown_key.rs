@@ -0,0 +1,6 @@
+// The path is relative to the test crate in `target/tests/trybuild/rust-i18n`
+rust_i18n::i18n!("../../../../tests/locales", fallback = "en", strict = true);
+
+fn main() {
+    let _ = rust_i18n::t!("mesages.hello", name = "Jason");
+}